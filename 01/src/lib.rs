@@ -0,0 +1,387 @@
+use std::sync::OnceLock;
+
+use aoc_common::{debug, trace, AocError, Detail, Example, ParseError, Part, Solution};
+
+mod scanner;
+mod stream;
+mod words;
+
+pub use scanner::{DigitMatch, DigitSource, Scanner};
+pub use stream::add_number_reader;
+pub use words::{NumberWords, LOCALES};
+
+const EXAMPLE_DIGITS: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+const EXAMPLE_WORDS: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+/// Which characters count as a digit of the calibration value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitRecognizer {
+    /// Only the digits 0-9, for part one
+    Digits,
+    /// 0-9 and the spelled-out one-nine, for part two, see `NumberWords` for other languages
+    DigitsAndWords,
+}
+
+/// What to do with a line without any digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Stop at the line with an error, corrupted input should not give a plausible answer
+    Strict,
+    /// Skip the line and report it in the `Calibration`
+    Lenient,
+}
+
+/// The sum of the calibration values of a document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    pub sum: u64,
+    /// The 1-based numbers of the skipped lines, only in `Strictness::Lenient`
+    pub lines_without_digits: Vec<usize>,
+}
+
+impl Calibration {
+    /// Add the calibration of the lines that follow, `last_line` is the last of them
+    pub fn merge(&mut self, other: Calibration, last_line: usize) -> Result<(), AocError> {
+        self.sum = checked_sum(self.sum, other.sum, last_line)?;
+        self.lines_without_digits.extend(other.lines_without_digits);
+
+        Ok(())
+    }
+}
+
+fn checked_sum(sum: u64, value: u64, line_number: usize) -> Result<u64, AocError> {
+    sum.checked_add(value).ok_or_else(|| {
+        AocError::Overflow(format!(
+            "The sum of the calibration values overflows at line {}",
+            line_number
+        ))
+    })
+}
+
+pub(crate) const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+impl DigitRecognizer {
+    /// The scanner for the recognised patterns, built on first use
+    pub fn scanner(self) -> &'static Scanner {
+        static DIGITS_SCANNER: OnceLock<Scanner> = OnceLock::new();
+        static DIGITS_AND_WORDS_SCANNER: OnceLock<Scanner> = OnceLock::new();
+
+        match self {
+            DigitRecognizer::Digits => DIGITS_SCANNER.get_or_init(|| Scanner::new(&DIGITS)),
+            DigitRecognizer::DigitsAndWords => {
+                DIGITS_AND_WORDS_SCANNER.get_or_init(|| NumberWords::english().scanner())
+            }
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<&'a str>;
+    type Answer = u64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE_DIGITS,
+            part_one: Some("142"),
+            part_two: Some("142"),
+        },
+        Example {
+            input: EXAMPLE_WORDS,
+            part_one: None,
+            part_two: Some("281"),
+        },
+    ];
+
+    fn parse(raw_input: &str) -> Result<Vec<&str>, AocError> {
+        Ok(raw_input.lines().collect())
+    }
+
+    /// Sum the calibration values of every line, recognising only digits
+    fn part_one(lines: &Vec<&str>) -> Result<u64, AocError> {
+        let calibration =
+            add_number_strings(lines, DigitRecognizer::Digits.scanner(), Strictness::Strict)?;
+
+        Ok(calibration.sum)
+    }
+
+    /// Sum the calibration values of every line, recognising both digits and spelled-out digits
+    fn part_two(lines: &Vec<&str>) -> Result<u64, AocError> {
+        let calibration = add_number_strings(
+            lines,
+            DigitRecognizer::DigitsAndWords.scanner(),
+            Strictness::Strict,
+        )?;
+
+        Ok(calibration.sum)
+    }
+
+    /// The calibration value of every line
+    fn details(lines: &Vec<&str>, part: Part) -> Result<Vec<Detail>, AocError> {
+        let recognizer = match part {
+            Part::One => DigitRecognizer::Digits,
+            Part::Two => DigitRecognizer::DigitsAndWords,
+        };
+
+        Ok(lines
+            .iter()
+            .map(|&line| {
+                let value = get_first_and_last_digits(line, recognizer.scanner())
+                    .map_or("no digits".to_string(), |value| value.to_string());
+
+                Detail::new(line, value)
+            })
+            .collect())
+    }
+}
+
+/// Every digit the scanner recognises in the line, overlapping words included
+///
+/// "eightwo3" => 8 at 0..5, 2 at 4..7 and 3 at 7..8
+pub fn extract_digits(line: &str, scanner: &Scanner) -> Vec<DigitMatch> {
+    scanner.matches(line)
+}
+
+/// The first and the last of the `extract_digits` as a two-digit number, `None` without digits
+///
+/// The scanner only looks for those two, from both ends of the line, instead of collecting all of
+/// them, and builds them like the matches of `extract_digits`.
+pub fn get_first_and_last_digits(str: &str, scanner: &Scanner) -> Option<u32> {
+    let first_number = scanner.first(str)?.value;
+    trace!("first_number {}", first_number);
+
+    let last_number = scanner.last(str)?.value;
+    trace!("last_number {}", last_number);
+
+    Some(first_number * 10 + last_number)
+}
+
+/// Sum the calibration values of the lines, an empty document sums to 0
+///
+/// See `add_number_reader` for inputs that do not fit in memory.
+pub fn add_number_strings<I, S>(
+    strings: I,
+    scanner: &Scanner,
+    strictness: Strictness,
+) -> Result<Calibration, AocError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    sum_lines(strings, 1, scanner, strictness)
+}
+
+/// Sum the calibration values of lines that start at `first_line_number`
+pub(crate) fn sum_lines<I, S>(
+    lines: I,
+    first_line_number: usize,
+    scanner: &Scanner,
+    strictness: Strictness,
+) -> Result<Calibration, AocError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut calibration = Calibration::default();
+
+    for (index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref();
+        let line_number = first_line_number + index;
+
+        let Some(value) = get_first_and_last_digits(line, scanner) else {
+            match strictness {
+                Strictness::Strict => {
+                    let error = ParseError::new(line, "a line with a digit");
+                    return Err(error.locate_in_line(line_number, line).into());
+                }
+                Strictness::Lenient => {
+                    debug!("skipping line {} without digits", line_number);
+                    calibration.lines_without_digits.push(line_number);
+                    continue;
+                }
+            }
+        };
+
+        calibration.sum = checked_sum(calibration.sum, u64::from(value), line_number)?;
+    }
+
+    Ok(calibration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_first_and_last_digits_test() {
+        let digits = DigitRecognizer::Digits.scanner();
+        let words = DigitRecognizer::DigitsAndWords.scanner();
+
+        // Part one
+        assert_eq!(get_first_and_last_digits("1abc2", digits), Some(12));
+        assert_eq!(get_first_and_last_digits("pqr3stu8vwx", digits), Some(38));
+        assert_eq!(get_first_and_last_digits("a1b2c3d4e5f", digits), Some(15));
+        assert_eq!(get_first_and_last_digits("treb7uchet", digits), Some(77));
+        assert_eq!(get_first_and_last_digits("two1nine", digits), Some(11));
+        assert_eq!(get_first_and_last_digits("7pqrstsixteen", digits), Some(77));
+        assert_eq!(get_first_and_last_digits("a0b5c", digits), Some(5));
+
+        // Part two
+        assert_eq!(get_first_and_last_digits("two1nine", words), Some(29));
+        assert_eq!(get_first_and_last_digits("eightwothree", words), Some(83));
+        assert_eq!(
+            get_first_and_last_digits("abcone2threexyz", words),
+            Some(13)
+        );
+        assert_eq!(get_first_and_last_digits("xtwone3four", words), Some(24));
+        assert_eq!(
+            get_first_and_last_digits("4nineeightseven2", words),
+            Some(42)
+        );
+        assert_eq!(get_first_and_last_digits("zoneight234", words), Some(14));
+        assert_eq!(get_first_and_last_digits("7pqrstsixteen", words), Some(76));
+    }
+
+    #[test]
+    fn add_number_strings_test() {
+        // Part one
+        let numbers = vec!["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"];
+        let result = add_number_strings(
+            numbers,
+            DigitRecognizer::Digits.scanner(),
+            Strictness::Strict,
+        );
+        assert_eq!(result.unwrap().sum, 142);
+
+        // Part two
+        let numbers = vec![
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "4nineeightseven2",
+            "zoneight234",
+            "7pqrstsixteen",
+        ];
+        let result = add_number_strings(
+            numbers,
+            DigitRecognizer::DigitsAndWords.scanner(),
+            Strictness::Strict,
+        );
+        assert_eq!(result.unwrap().sum, 281);
+    }
+
+    #[test]
+    fn can_scan_words() {
+        let scanner = DigitRecognizer::DigitsAndWords.scanner();
+
+        assert_eq!(scanner.first("one").map(|found| found.value), Some(1));
+        assert_eq!(scanner.first("kkone").map(|found| found.value), Some(1));
+        assert_eq!(scanner.last("33two").map(|found| found.value), Some(2));
+        assert_eq!(scanner.first("three").map(|found| found.value), Some(3));
+        assert_eq!(scanner.first("four").map(|found| found.value), Some(4));
+        assert_eq!(scanner.first("five").map(|found| found.value), Some(5));
+        assert_eq!(scanner.last("k8six").map(|found| found.value), Some(6));
+        assert_eq!(scanner.first("seven").map(|found| found.value), Some(7));
+        assert_eq!(scanner.first("eight").map(|found| found.value), Some(8));
+        assert_eq!(scanner.first("nine").map(|found| found.value), Some(9));
+        assert_eq!(
+            DigitRecognizer::Digits
+                .scanner()
+                .first("kkone")
+                .map(|found| found.value),
+            None
+        );
+    }
+
+    #[test]
+    fn can_read_overlapping_words() {
+        let words = DigitRecognizer::DigitsAndWords.scanner();
+
+        assert_eq!(get_first_and_last_digits("eightwo", words), Some(82));
+        assert_eq!(get_first_and_last_digits("oneight", words), Some(18));
+        assert_eq!(get_first_and_last_digits("twone", words), Some(21));
+        assert_eq!(get_first_and_last_digits("nothing", words), None);
+    }
+
+    #[test]
+    fn can_extract_every_digit() {
+        let words = DigitRecognizer::DigitsAndWords.scanner();
+        let digits = extract_digits("4nineightseven2", words);
+
+        assert_eq!(
+            digits.iter().map(|found| found.value).collect::<Vec<_>>(),
+            [4, 9, 8, 7, 2]
+        );
+        assert_eq!(digits[1].span, 1..5);
+        assert_eq!(digits[2].span, 4..9);
+        assert_eq!(digits[4].source, DigitSource::Digit);
+
+        // Another calibration rule: the sum of all digits
+        assert_eq!(digits.iter().map(|found| found.value).sum::<u32>(), 30);
+
+        for line in EXAMPLE_WORDS.lines() {
+            let digits = extract_digits(line, words);
+            let expected = digits[0].value * 10 + digits[digits.len() - 1].value;
+
+            assert_eq!(get_first_and_last_digits(line, words), Some(expected));
+            assert_eq!(words.first(line).as_ref(), digits.first());
+            assert_eq!(words.last(line).as_ref(), digits.last());
+        }
+
+        // The spans of the first and the last match agree when the case is folded too
+        let german = NumberWords::german().ignoring_case().scanner();
+        let line = "xEINSzweiFÜNFy";
+        let digits = extract_digits(line, &german);
+        assert_eq!(german.first(line).as_ref(), digits.first());
+        assert_eq!(german.last(line).as_ref(), digits.last());
+        assert_eq!(digits.last().unwrap().span, 9..14);
+    }
+
+    #[test]
+    fn reports_lines_without_digits() {
+        let digits = DigitRecognizer::Digits.scanner();
+        let lines = vec!["1abc2", "eightwothree", "treb7uchet", ""];
+
+        let error = add_number_strings(lines.clone(), digits, Strictness::Strict).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("2:1: expected a line with a digit, found \"eightwothree\""));
+
+        let calibration = add_number_strings(lines, digits, Strictness::Lenient).unwrap();
+        assert_eq!(calibration.sum, 89);
+        assert_eq!(calibration.lines_without_digits, [2, 4]);
+
+        assert_eq!(
+            add_number_strings(Vec::<&str>::new(), digits, Strictness::Strict).unwrap(),
+            Calibration {
+                sum: 0,
+                lines_without_digits: vec![]
+            }
+        );
+    }
+}
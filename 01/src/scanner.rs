@@ -0,0 +1,389 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Whether a digit was written as a digit or spelled out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitSource {
+    Digit,
+    Word,
+}
+
+/// A recognised digit, `span` is the byte range of the digit or the word in the line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitMatch {
+    pub value: u32,
+    pub span: Range<usize>,
+    pub source: DigitSource,
+}
+
+impl DigitMatch {
+    fn new(line: &str, value: u32, span: Range<usize>) -> DigitMatch {
+        let source = match line.as_bytes()[span.clone()] {
+            [byte] if byte.is_ascii_digit() => DigitSource::Digit,
+            _ => DigitSource::Word,
+        };
+
+        DigitMatch {
+            value,
+            span,
+            source,
+        }
+    }
+}
+
+/// Finds the first and the last of many patterns in a line, in one pass from each end
+///
+/// The patterns are compiled into two Aho-Corasick automatons, one for the patterns
+/// and one for the patterns reversed, so scanning does not allocate. Matches may
+/// overlap: in "eightwo" the first match is "eight" and the last one is "two".
+pub struct Scanner {
+    forward: Automaton,
+    backward: Automaton,
+    ignore_case: bool,
+}
+
+impl Scanner {
+    /// `patterns` are (pattern, value) pairs, empty patterns are ignored
+    pub fn new(patterns: &[(&str, u32)]) -> Scanner {
+        Scanner::build(patterns, false)
+    }
+
+    /// Like `new`, but "One", "ONE" and "one" are the same pattern, also outside of ASCII
+    pub fn ignoring_case(patterns: &[(&str, u32)]) -> Scanner {
+        Scanner::build(patterns, true)
+    }
+
+    fn build(patterns: &[(&str, u32)], ignore_case: bool) -> Scanner {
+        let forward: Vec<(Vec<u8>, u32)> = patterns
+            .iter()
+            .map(|(pattern, value)| {
+                let pattern = if ignore_case {
+                    pattern.to_lowercase()
+                } else {
+                    pattern.to_string()
+                };
+
+                (pattern.into_bytes(), *value)
+            })
+            .collect();
+        let backward: Vec<(Vec<u8>, u32)> = forward
+            .iter()
+            .map(|(pattern, value)| (pattern.iter().rev().copied().collect(), *value))
+            .collect();
+
+        Scanner {
+            forward: Automaton::new(&forward),
+            backward: Automaton::new(&backward),
+            ignore_case,
+        }
+    }
+
+    /// Every match in the line ordered by their spans, overlapping matches included
+    pub fn matches(&self, line: &str) -> Vec<DigitMatch> {
+        let mut matches = if self.ignore_case {
+            self.all(line, folded_bytes(line))
+        } else {
+            self.all(
+                line,
+                line.bytes().enumerate().map(|(i, byte)| (i + 1, byte)),
+            )
+        };
+
+        matches.sort_by_key(|found| (found.span.start, found.span.end));
+        matches
+    }
+
+    /// The match that starts first
+    pub fn first(&self, line: &str) -> Option<DigitMatch> {
+        let found = if self.ignore_case {
+            self.forward.leftmost(folded_bytes(line))
+        } else {
+            self.forward
+                .leftmost(line.bytes().enumerate().map(|(i, byte)| (i + 1, byte)))
+        };
+
+        found.map(|(end, len, value)| self.found(line, value, end, len))
+    }
+
+    /// The match that ends last
+    pub fn last(&self, line: &str) -> Option<DigitMatch> {
+        let found = if self.ignore_case {
+            self.backward.leftmost(folded_bytes_reversed(line))
+        } else {
+            self.backward.leftmost(line.bytes().enumerate().rev())
+        };
+
+        found.map(|(start, len, value)| self.found(line, value, self.end(line, start, len), len))
+    }
+
+    /// `bytes` are (end of the character in the line, byte) pairs
+    fn all<I: Iterator<Item = (usize, u8)>>(&self, line: &str, bytes: I) -> Vec<DigitMatch> {
+        let mut matches = vec![];
+        let mut state = ROOT;
+
+        for (end, byte) in bytes {
+            state = self.forward.transitions[state][byte as usize];
+
+            for &(len, value) in &self.forward.outputs[state] {
+                matches.push(self.found(line, value, end, len));
+            }
+        }
+
+        matches
+    }
+
+    /// The match of `len` scanned bytes that ends at `end`, every match is built here so
+    /// `first` and `last` give the same spans as `matches`
+    fn found(&self, line: &str, value: u32, end: usize, len: usize) -> DigitMatch {
+        DigitMatch::new(line, value, self.start(line, end, len)..end)
+    }
+
+    /// The start in the line of a match of `len` scanned bytes that ends at `end`
+    fn start(&self, line: &str, end: usize, len: usize) -> usize {
+        if !self.ignore_case {
+            return end - len;
+        }
+
+        let mut folded = 0;
+
+        for (i, ch) in line[..end].char_indices().rev() {
+            folded += folded_len(ch);
+
+            if folded >= len {
+                return i;
+            }
+        }
+
+        0
+    }
+
+    /// The end in the line of a match of `len` scanned bytes that starts at `start`
+    fn end(&self, line: &str, start: usize, len: usize) -> usize {
+        if !self.ignore_case {
+            return start + len;
+        }
+
+        let mut folded = 0;
+
+        for (i, ch) in line[start..].char_indices() {
+            folded += folded_len(ch);
+
+            if folded >= len {
+                return start + i + ch.len_utf8();
+            }
+        }
+
+        line.len()
+    }
+}
+
+/// The lowercase bytes with the end of the character they come from
+fn folded_bytes(line: &str) -> impl Iterator<Item = (usize, u8)> + '_ {
+    line.char_indices().flat_map(|(i, ch)| {
+        let end = i + ch.len_utf8();
+
+        ch.to_lowercase()
+            .flat_map(utf8)
+            .map(move |byte| (end, byte))
+    })
+}
+
+/// The lowercase bytes from the end with the start of the character they come from
+fn folded_bytes_reversed(line: &str) -> impl Iterator<Item = (usize, u8)> + '_ {
+    line.char_indices().rev().flat_map(|(i, ch)| {
+        ch.to_lowercase()
+            .rev()
+            .flat_map(|ch| utf8(ch).rev())
+            .map(move |byte| (i, byte))
+    })
+}
+
+/// The length of the character in lowercase
+fn folded_len(ch: char) -> usize {
+    ch.to_lowercase().map(char::len_utf8).sum()
+}
+
+/// The UTF-8 bytes of the character, without allocating
+fn utf8(ch: char) -> std::iter::Take<std::array::IntoIter<u8, 4>> {
+    let mut bytes = [0; 4];
+    let len = ch.encode_utf8(&mut bytes).len();
+
+    bytes.into_iter().take(len)
+}
+
+const ROOT: usize = 0;
+
+/// A deterministic automaton over bytes, every state has a transition for every byte
+struct Automaton {
+    transitions: Vec<[usize; 256]>,
+    /// (length, value) of every pattern that ends in the state
+    outputs: Vec<Vec<(usize, u32)>>,
+    max_len: usize,
+}
+
+impl Automaton {
+    fn new(patterns: &[(Vec<u8>, u32)]) -> Automaton {
+        let mut transitions: Vec<[usize; 256]> = vec![[ROOT; 256]];
+        let mut has_transition: Vec<[bool; 256]> = vec![[false; 256]];
+        let mut outputs: Vec<Vec<(usize, u32)>> = vec![vec![]];
+
+        // The trie of the patterns
+        for (pattern, value) in patterns.iter().filter(|(pattern, _)| !pattern.is_empty()) {
+            let mut state = ROOT;
+
+            for &byte in pattern {
+                if !has_transition[state][byte as usize] {
+                    transitions.push([ROOT; 256]);
+                    has_transition.push([false; 256]);
+                    outputs.push(vec![]);
+
+                    transitions[state][byte as usize] = transitions.len() - 1;
+                    has_transition[state][byte as usize] = true;
+                }
+
+                state = transitions[state][byte as usize];
+            }
+
+            outputs[state].push((pattern.len(), *value));
+        }
+
+        // Breadth first, so the failure state of every state is done before the state itself
+        let mut failures: Vec<usize> = vec![ROOT; transitions.len()];
+        let mut queue: VecDeque<usize> = (0..256)
+            .filter(|&byte| has_transition[ROOT][byte])
+            .map(|byte| transitions[ROOT][byte])
+            .collect();
+
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+            let inherited = outputs[failure].clone();
+            outputs[state].extend(inherited);
+
+            for byte in 0..256 {
+                if has_transition[state][byte] {
+                    let next = transitions[state][byte];
+                    failures[next] = transitions[failure][byte];
+                    queue.push_back(next);
+                } else {
+                    transitions[state][byte] = transitions[failure][byte];
+                }
+            }
+        }
+
+        Automaton {
+            transitions,
+            outputs,
+            max_len: patterns
+                .iter()
+                .map(|(pattern, _)| pattern.len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    /// The (position, length, value) of the match that starts first in the bytes
+    ///
+    /// The bytes come with their position in the line, which is returned for the match.
+    fn leftmost<I: Iterator<Item = (usize, u8)>>(&self, bytes: I) -> Option<(usize, usize, u32)> {
+        let mut state = ROOT;
+        // The start of the best match in the scanned bytes and the match
+        let mut best: Option<(usize, (usize, usize, u32))> = None;
+
+        for (scanned, (position, byte)) in bytes.enumerate() {
+            // A match ending here starts after the best one, once it is this far away
+            if let Some((start, _)) = best {
+                if scanned >= start + self.max_len {
+                    break;
+                }
+            }
+
+            state = self.transitions[state][byte as usize];
+
+            for &(len, value) in &self.outputs[state] {
+                let start = scanned + 1 - len;
+
+                if best.is_none_or(|(best_start, _)| start < best_start) {
+                    best = Some((start, (position, len, value)));
+                }
+            }
+        }
+
+        best.map(|(_, found)| found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(found: Option<DigitMatch>) -> Option<u32> {
+        found.map(|found| found.value)
+    }
+
+    #[test]
+    fn can_find_overlapping_matches() {
+        let scanner = Scanner::new(&[("one", 1), ("two", 2), ("eight", 8), ("3", 3)]);
+
+        assert_eq!(value(scanner.first("eightwo")), Some(8));
+        assert_eq!(value(scanner.last("eightwo")), Some(2));
+        assert_eq!(value(scanner.first("xoneight")), Some(1));
+        assert_eq!(value(scanner.last("xoneight")), Some(8));
+        assert_eq!(value(scanner.first("on3e")), Some(3));
+        assert_eq!(value(scanner.last("abc")), None);
+    }
+
+    #[test]
+    fn prefers_the_earliest_start_over_the_earliest_end() {
+        // "bc" ends before "abcd" does, but "abcd" starts first
+        let scanner = Scanner::new(&[("abcd", 1), ("bc", 2)]);
+
+        assert_eq!(value(scanner.first("xabcd")), Some(1));
+        assert_eq!(value(scanner.last("abcdx")), Some(1));
+        assert_eq!(value(scanner.last("abcx")), Some(2));
+    }
+
+    #[test]
+    fn can_ignore_case() {
+        let scanner = Scanner::ignoring_case(&[("Fünf", 5), ("zwei", 2)]);
+
+        assert_eq!(value(scanner.first("xZWEIfünf")), Some(2));
+        assert_eq!(value(scanner.last("xZWEIFÜNFx")), Some(5));
+        assert_eq!(value(Scanner::new(&[("zwei", 2)]).first("ZWEI")), None);
+    }
+
+    #[test]
+    fn can_find_every_match_with_its_span() {
+        let scanner = Scanner::new(&[("one", 1), ("two", 2), ("eight", 8), ("3", 3)]);
+        let spans: Vec<(u32, Range<usize>, DigitSource)> = scanner
+            .matches("eightwo3ne")
+            .into_iter()
+            .map(|found| (found.value, found.span, found.source))
+            .collect();
+
+        assert_eq!(
+            spans,
+            [
+                (8, 0..5, DigitSource::Word),
+                (2, 4..7, DigitSource::Word),
+                (3, 7..8, DigitSource::Digit)
+            ]
+        );
+    }
+
+    #[test]
+    fn can_find_spans_when_ignoring_case() {
+        let scanner = Scanner::ignoring_case(&[("fünf", 5), ("zwei", 2)]);
+        let line = "xZWEIFÜNFx";
+
+        assert_eq!(scanner.first(line).unwrap().span, 1..5);
+        assert_eq!(scanner.last(line).unwrap().span, 5..10);
+        assert_eq!(&line[scanner.last(line).unwrap().span], "FÜNF");
+
+        let spans: Vec<Range<usize>> = scanner
+            .matches(line)
+            .into_iter()
+            .map(|found| found.span)
+            .collect();
+        assert_eq!(spans, [1..5, 5..10]);
+    }
+}
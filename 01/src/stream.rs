@@ -0,0 +1,166 @@
+use std::io::BufRead;
+use std::panic;
+use std::path::PathBuf;
+use std::thread;
+
+use aoc_common::{debug, AocError};
+
+use crate::{sum_lines, Calibration, Scanner, Strictness};
+
+/// The lines read at once, every thread sums one such chunk at a time
+const CHUNK_LINES: usize = 1 << 16;
+
+/// Sum the calibration values of the lines of a reader, without reading all of it into memory
+///
+/// With more than one thread, every thread sums its own chunk of lines. The chunks are
+/// combined in the order of the input, so the result and the first error do not depend
+/// on the number of threads. Read errors have no path, `AocError::in_file` adds it.
+pub fn add_number_reader<R: BufRead>(
+    mut reader: R,
+    scanner: &Scanner,
+    strictness: Strictness,
+    threads: usize,
+) -> Result<Calibration, AocError> {
+    let mut chunks: Vec<String> = vec![String::new(); threads.max(1)];
+    let mut calibration = Calibration::default();
+    let mut lines_before = 0;
+
+    loop {
+        // The number of lines in every chunk that was filled
+        let mut line_counts: Vec<usize> = vec![];
+
+        for chunk in chunks.iter_mut() {
+            chunk.clear();
+
+            let line_count = read_lines(&mut reader, chunk, CHUNK_LINES)?;
+
+            if line_count == 0 {
+                break;
+            }
+
+            line_counts.push(line_count);
+        }
+
+        if line_counts.is_empty() {
+            return Ok(calibration);
+        }
+
+        let first_lines: Vec<usize> = line_counts
+            .iter()
+            .scan(lines_before + 1, |first_line, &line_count| {
+                let first = *first_line;
+                *first_line += line_count;
+                Some(first)
+            })
+            .collect();
+
+        debug!(
+            "summing {} chunks from line {}",
+            line_counts.len(),
+            first_lines[0]
+        );
+
+        let results: Vec<Result<Calibration, AocError>> = if line_counts.len() == 1 {
+            vec![sum_lines(
+                chunks[0].lines(),
+                first_lines[0],
+                scanner,
+                strictness,
+            )]
+        } else {
+            thread::scope(|scope| {
+                let handles: Vec<_> = chunks
+                    .iter()
+                    .zip(&first_lines)
+                    .map(|(chunk, &first_line)| {
+                        scope.spawn(move || {
+                            sum_lines(chunk.lines(), first_line, scanner, strictness)
+                        })
+                    })
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|error| panic::resume_unwind(error))
+                    })
+                    .collect()
+            })
+        };
+
+        for ((result, first_line), line_count) in
+            results.into_iter().zip(first_lines).zip(&line_counts)
+        {
+            calibration.merge(result?, first_line + line_count - 1)?;
+        }
+
+        lines_before += line_counts.iter().sum::<usize>();
+    }
+}
+
+/// Append up to `max_lines` lines to the chunk, returns how many were read
+fn read_lines<R: BufRead>(
+    reader: &mut R,
+    chunk: &mut String,
+    max_lines: usize,
+) -> Result<usize, AocError> {
+    for line_count in 0..max_lines {
+        let read = reader.read_line(chunk).map_err(|source| AocError::Io {
+            path: PathBuf::new(),
+            source,
+        })?;
+
+        if read == 0 {
+            return Ok(line_count);
+        }
+    }
+
+    Ok(max_lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitRecognizer;
+
+    #[test]
+    fn gives_the_same_result_on_any_number_of_threads() {
+        let words = DigitRecognizer::DigitsAndWords.scanner();
+        let lines: Vec<String> = (0..CHUNK_LINES * 2 + 7)
+            .map(|i| match i % 4 {
+                0 => format!("{}abc{}", i % 10, (i / 10) % 10),
+                1 => "eightwothree".to_string(),
+                2 => "no digits\r".to_string(),
+                _ => format!("x{}y", i % 7),
+            })
+            .collect();
+        let input = lines.join("\n");
+
+        let expected =
+            crate::add_number_strings(input.lines(), words, Strictness::Lenient).unwrap();
+
+        for threads in [1, 2, 3, 8] {
+            let found =
+                add_number_reader(input.as_bytes(), words, Strictness::Lenient, threads).unwrap();
+
+            assert_eq!(found, expected);
+        }
+
+        let error = add_number_reader(input.as_bytes(), words, Strictness::Strict, 4).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("3:1: expected a line with a digit"));
+    }
+
+    #[test]
+    fn can_read_an_empty_input() {
+        let digits = DigitRecognizer::Digits.scanner();
+
+        assert_eq!(
+            add_number_reader("".as_bytes(), digits, Strictness::Strict, 2).unwrap(),
+            Calibration::default()
+        );
+    }
+}
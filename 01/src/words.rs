@@ -0,0 +1,221 @@
+use std::path::Path;
+
+use aoc_common::input::read_file;
+use aoc_common::parse::{parse_number, split_once_or_error};
+use aoc_common::{AocError, ParseError};
+
+use crate::{Scanner, DIGITS};
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const DUTCH: [(&str, u32); 9] = [
+    ("een", 1),
+    ("twee", 2),
+    ("drie", 3),
+    ("vier", 4),
+    ("vijf", 5),
+    ("zes", 6),
+    ("zeven", 7),
+    ("acht", 8),
+    ("negen", 9),
+];
+
+const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+/// The locales with a built-in table
+pub const LOCALES: [&str; 3] = ["en", "nl", "de"];
+
+/// The spelled-out digits of a language, they count as digits next to 0-9
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberWords {
+    words: Vec<(String, u32)>,
+    ignore_case: bool,
+}
+
+impl NumberWords {
+    fn from_table(table: &[(&str, u32)]) -> NumberWords {
+        NumberWords {
+            words: table
+                .iter()
+                .map(|(word, value)| (word.to_string(), *value))
+                .collect(),
+            ignore_case: false,
+        }
+    }
+
+    /// one … nine, the words of the puzzle
+    pub fn english() -> NumberWords {
+        NumberWords::from_table(&ENGLISH)
+    }
+
+    /// een … negen
+    pub fn dutch() -> NumberWords {
+        NumberWords::from_table(&DUTCH)
+    }
+
+    /// eins … neun
+    pub fn german() -> NumberWords {
+        NumberWords::from_table(&GERMAN)
+    }
+
+    /// The built-in table of one of the `LOCALES`
+    pub fn for_locale(locale: &str) -> Result<NumberWords, AocError> {
+        match locale {
+            "en" => Ok(NumberWords::english()),
+            "nl" => Ok(NumberWords::dutch()),
+            "de" => Ok(NumberWords::german()),
+            locale => Err(AocError::Unsupported(format!(
+                "Unknown locale {:?}, expected one of {}",
+                locale,
+                LOCALES.join(", ")
+            ))),
+        }
+    }
+
+    /// One `word = digit` per line, empty lines and lines starting with `#` are skipped
+    ///
+    /// ```text
+    /// # Dutch
+    /// een = 1
+    /// twee = 2
+    /// ```
+    pub fn parse(str: &str) -> Result<NumberWords, ParseError> {
+        let mut words = vec![];
+
+        for line in str.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (word, value) = split_once_or_error(line, '=')?;
+            let (word, value) = (word.trim(), value.trim());
+
+            if word.is_empty() {
+                return Err(ParseError::new(word, "a word"));
+            }
+
+            let digit: u32 = parse_number(value)?;
+
+            if digit > 9 {
+                return Err(ParseError::new(value, "a digit from 0 to 9"));
+            }
+
+            words.push((word.to_string(), digit));
+        }
+
+        Ok(NumberWords {
+            words,
+            ignore_case: false,
+        })
+    }
+
+    /// Read a table in the format of `parse`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<NumberWords, AocError> {
+        let table = read_file(&path)?;
+
+        NumberWords::parse(&table)
+            .map_err(|error| AocError::from(error.locate(&table)).in_file(&path))
+    }
+
+    /// Match the words in any case, like "Drei" and "DREI" for "drei"
+    pub fn ignoring_case(mut self) -> NumberWords {
+        self.ignore_case = true;
+        self
+    }
+
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    /// A scanner for the words and the digits 0-9
+    pub fn scanner(&self) -> Scanner {
+        let patterns: Vec<(&str, u32)> = DIGITS
+            .iter()
+            .copied()
+            .chain(
+                self.words
+                    .iter()
+                    .map(|(word, value)| (word.as_str(), *value)),
+            )
+            .collect();
+
+        if self.ignore_case {
+            Scanner::ignoring_case(&patterns)
+        } else {
+            Scanner::new(&patterns)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_first_and_last_digits;
+
+    #[test]
+    fn can_use_any_locale() {
+        let dutch = NumberWords::for_locale("nl").unwrap().scanner();
+        assert_eq!(get_first_and_last_digits("xtweevijfacht", &dutch), Some(28));
+        assert_eq!(get_first_and_last_digits("zevenegen", &dutch), Some(79));
+
+        let german = NumberWords::for_locale("de").unwrap().scanner();
+        assert_eq!(
+            get_first_and_last_digits("sechsiebenfünf", &german),
+            Some(65)
+        );
+        assert_eq!(get_first_and_last_digits("Eins4", &german), Some(44));
+
+        let german = NumberWords::german().ignoring_case().scanner();
+        assert_eq!(get_first_and_last_digits("EinsZWEIFÜNF", &german), Some(15));
+
+        assert!(NumberWords::for_locale("fr").is_err());
+    }
+
+    #[test]
+    fn can_parse_a_table() {
+        let table = "# Roman\n\ni = 1\n  viii = 8\n";
+        let words = NumberWords::parse(table).unwrap();
+        assert_eq!(
+            words.words(),
+            [("i".to_string(), 1), ("viii".to_string(), 8)]
+        );
+
+        // The longest word is not limited to five letters
+        let words = NumberWords::parse("dreiundzwanzig = 3\nx = 1").unwrap();
+        assert_eq!(
+            get_first_and_last_digits("xdreiundzwanzig", &words.scanner()),
+            Some(13)
+        );
+    }
+
+    #[test]
+    fn reports_malformed_tables() {
+        let table = "one = 1\ntwo 2";
+        let error = NumberWords::parse(table).unwrap_err().locate(table);
+        assert_eq!((error.line, error.expected.as_str()), (2, "'='"));
+
+        let table = "ten = 10";
+        let error = NumberWords::parse(table).unwrap_err().locate(table);
+        assert_eq!((error.column, error.found.as_str()), (7, "10"));
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use aoc_common::input::read_file;
+use aoc_common::parse::{parse_number, split_once_or_error};
+use aoc_common::{AocError, ParseError};
+
+use crate::{Color, Game};
+
+/// How many cubes of every color the bag holds, a hypothesis to check the games against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<Color, u32>,
+}
+
+impl Bag {
+    /// 12 red, 13 green and 14 blue cubes, the bag of part one
+    pub fn puzzle() -> Bag {
+        Bag {
+            cubes: [(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)].into(),
+        }
+    }
+
+    /// "red=12,green=13,blue=14,yellow=3"
+    pub fn parse(str: &str) -> Result<Bag, ParseError> {
+        let mut cubes = BTreeMap::new();
+
+        for cube_str in str.split(',') {
+            let (color, quantity) = split_once_or_error(cube_str, '=')?;
+
+            cubes.insert(Color::parse(color.trim())?, parse_number(quantity.trim())?);
+        }
+
+        Ok(Bag { cubes })
+    }
+
+    /// One bag per line in the format of `parse`, empty lines and lines starting with `#` are skipped
+    pub fn parse_list(str: &str) -> Result<Vec<Bag>, ParseError> {
+        str.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(Bag::parse)
+            .collect()
+    }
+
+    /// Read the bags of a file in the format of `parse_list`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Bag>, AocError> {
+        let bags = read_file(&path)?;
+
+        Bag::parse_list(&bags).map_err(|error| AocError::from(error.locate(&bags)).in_file(&path))
+    }
+
+    /// The cubes of the color, `None` when the bag has no such color
+    pub fn get(&self, color: &Color) -> Option<u32> {
+        self.cubes.get(color).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Color, u32)> {
+        self.cubes
+            .iter()
+            .map(|(color, &quantity)| (color, quantity))
+    }
+}
+
+impl FromIterator<(Color, u32)> for Bag {
+    fn from_iter<I: IntoIterator<Item = (Color, u32)>>(cubes: I) -> Bag {
+        Bag {
+            cubes: cubes.into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = self
+            .cubes
+            .iter()
+            .map(|(color, quantity)| format!("{}={}", color, quantity))
+            .collect();
+
+        write!(f, "{}", cubes.join(","))
+    }
+}
+
+/// What to do with a grab that shows a color that is not in the bag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Stop with an error
+    Strict,
+    /// Ignore the color and report it in the `BagCheck`
+    Lenient,
+}
+
+/// The games that are possible with a bag
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BagCheck {
+    pub possible: Vec<u32>,
+    /// The (game id, color) of every color that is not in the bag, only in `Strictness::Lenient`
+    pub unknown_colors: Vec<(u32, Color)>,
+}
+
+impl BagCheck {
+    /// The sum of the ids of the possible games, the answer of part one
+    pub fn sum(&self) -> u32 {
+        self.possible.iter().sum()
+    }
+}
+
+pub fn check_bag(games: &[Game], bag: &Bag, strictness: Strictness) -> Result<BagCheck, AocError> {
+    let mut check = BagCheck::default();
+
+    for game in games {
+        let unknown_colors = game.unknown_colors(bag);
+
+        if let (Some(color), Strictness::Strict) = (unknown_colors.first(), strictness) {
+            return Err(AocError::Invalid(format!(
+                "Game {} shows {} cubes, but the bag {} has none",
+                game.id, color, bag
+            )));
+        }
+
+        check.unknown_colors.extend(
+            unknown_colors
+                .into_iter()
+                .map(|color| (game.id, color.clone())),
+        );
+
+        if game.is_game_valid(bag) {
+            check.possible.push(game.id);
+        }
+    }
+
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_games, EXAMPLE};
+
+    #[test]
+    fn can_check_several_bags() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let bags =
+            Bag::parse_list("# Part one\nred=12,green=13,blue=14\n\nred=20, green=13, blue=15\n")
+                .unwrap();
+
+        assert_eq!(bags[0], Bag::puzzle());
+        assert_eq!(bags[1].to_string(), "red=20,green=13,blue=15");
+
+        let possible: Vec<Vec<u32>> = bags
+            .iter()
+            .map(|bag| check_bag(&games, bag, Strictness::Strict).unwrap().possible)
+            .collect();
+
+        assert_eq!(possible, [vec![1, 2, 5], vec![1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn reports_colors_that_are_not_in_the_bag() {
+        let games =
+            parse_games("Game 1: 2 yellow, 1 red\nGame 2: 4 red; 9 yellow, 1 pink").unwrap();
+        let bag = Bag::parse("red=3,yellow=5").unwrap();
+
+        let check = check_bag(&games, &bag, Strictness::Lenient).unwrap();
+        assert_eq!(check.possible, [1]);
+        assert_eq!(
+            check.unknown_colors,
+            [(2, Color::Other("pink".to_string()))]
+        );
+
+        let error = check_bag(&games, &bag, Strictness::Strict).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Game 2 shows pink cubes, but the bag red=3,yellow=5 has none"
+        );
+
+        let error = check_bag(&games, &Bag::puzzle(), Strictness::Strict).unwrap_err();
+        assert!(error.to_string().starts_with("Game 1 shows yellow cubes"));
+    }
+
+    #[test]
+    fn reports_malformed_bags() {
+        let bags = "red=12\nred 12";
+        let error = Bag::parse_list(bags).unwrap_err().locate(bags);
+
+        assert_eq!((error.line, error.expected.as_str()), (2, "'='"));
+    }
+}
@@ -0,0 +1,442 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use aoc_common::parse::{parse_number, split_once_or_error};
+use aoc_common::{trace, AocError, Detail, Example, ParseError, Part, Solution};
+
+mod bag;
+mod query;
+mod report;
+pub mod simulation;
+
+pub use bag::{check_bag, Bag, BagCheck, Strictness};
+pub use query::{Query, QueryResult};
+pub use report::{find_violations, infer_bag, BagReport, Constraint, GrabRef, Violation};
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+/// The colors of the puzzle have their own variants, any other word is a color too
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+    Other(String),
+}
+
+impl Color {
+    /// The colors of the puzzle
+    pub const BASIC: [Color; 3] = [Color::Red, Color::Green, Color::Blue];
+
+    pub fn parse(str: &str) -> Result<Color, ParseError> {
+        match str {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ if !str.is_empty() && str.chars().all(char::is_alphabetic) => {
+                Ok(Color::Other(str.to_string()))
+            }
+            _ => Err(ParseError::new(str, "a color")),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Blue => "blue",
+            Color::Other(name) => name,
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// The cubes of every color shown in one grab, colors that are not mentioned have none
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grab {
+    pub cubes: BTreeMap<Color, u32>,
+}
+
+impl Grab {
+    pub fn get(&self, color: &Color) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// "3 blue, 4 red", a color that is mentioned twice is added up
+    pub fn parse(str: &str) -> Result<Grab, ParseError> {
+        let mut grab = Grab::default();
+
+        for cube_str in str.split(',').map(|x| x.trim()) {
+            let mut qty_and_color = cube_str.split_whitespace();
+            let quantity = qty_and_color
+                .next()
+                .ok_or_else(|| ParseError::new(cube_str, "a number"))?;
+            let quantity: u32 = parse_number(quantity)?;
+            let color = qty_and_color
+                .next()
+                .ok_or_else(|| ParseError::new(&cube_str[cube_str.len()..], "a color"))?;
+
+            if let Some(extra) = qty_and_color.next() {
+                return Err(ParseError::new(extra, "',' or ';'"));
+            }
+
+            *grab.cubes.entry(Color::parse(color)?).or_insert(0) += quantity;
+        }
+
+        Ok(grab)
+    }
+
+    /// Whether the bag holds enough cubes of every color, colors that are not in the bag are ignored
+    pub fn fits_in(&self, bag: &Bag) -> bool {
+        self.cubes.iter().all(|(color, &quantity)| {
+            let Some(limit) = bag.get(color) else {
+                return true;
+            };
+
+            let result = quantity <= limit;
+            trace!("Color: {}, qty: {}, result: {}", color, quantity, result);
+
+            result
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub grabs: Vec<Grab>,
+}
+
+impl Game {
+    /// "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+    pub fn parse(line: &str) -> Result<Game, ParseError> {
+        let (game, grabs) = split_once_or_error(line, ':')?;
+
+        let id = game
+            .trim()
+            .strip_prefix("Game")
+            .ok_or_else(|| ParseError::new(game, "\"Game\""))?;
+
+        Ok(Game {
+            id: parse_number(id.trim())?,
+            grabs: grabs
+                .split(';')
+                .map(|grab| Grab::parse(grab.trim()))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Whether the game is possible with the cubes in the bag, see `Grab::fits_in`
+    pub fn is_game_valid(&self, bag: &Bag) -> bool {
+        self.grabs.iter().all(|grab| grab.fits_in(bag))
+    }
+
+    /// The colors of the game that are not in the bag
+    pub fn unknown_colors<'a>(&'a self, bag: &Bag) -> Vec<&'a Color> {
+        let mut colors: Vec<&Color> = self
+            .grabs
+            .iter()
+            .flat_map(|grab| grab.cubes.keys())
+            .filter(|color| bag.get(color).is_none())
+            .collect();
+
+        colors.sort();
+        colors.dedup();
+        colors
+    }
+
+    /// The fewest cubes of every color the bag must hold for the game, red, green and blue
+    /// are always included
+    pub fn get_minimum_cubes(&self) -> Grab {
+        let mut minimum_cubes = Grab {
+            cubes: Color::BASIC.into_iter().map(|color| (color, 0)).collect(),
+        };
+
+        for grab in &self.grabs {
+            for (color, &quantity) in &grab.cubes {
+                let minimum = minimum_cubes.cubes.entry(color.clone()).or_insert(0);
+                *minimum = (*minimum).max(quantity);
+            }
+        }
+
+        minimum_cubes
+    }
+
+    /// The minimum cubes of red, green and blue multiplied together, other colors are not
+    /// part of the power. None when the power does not fit in a u64, which large generated
+    /// games can reach
+    pub fn get_power_of_minimum_cubes(&self) -> Option<u64> {
+        let minimum_cubes = self.get_minimum_cubes();
+
+        Color::BASIC.iter().try_fold(1u64, |power, color| {
+            power.checked_mul(minimum_cubes.get(color) as u64)
+        })
+    }
+
+    /// `get_power_of_minimum_cubes`, an overflow is an error
+    pub fn checked_power(&self) -> Result<u64, AocError> {
+        self.get_power_of_minimum_cubes()
+            .ok_or_else(|| AocError::Overflow(format!("The power of game {} overflows", self.id)))
+    }
+}
+
+/// One game per line
+pub fn parse_games(raw_input: &str) -> Result<Vec<Game>, ParseError> {
+    raw_input
+        .lines()
+        .map(|line| Game::parse(line).map_err(|error| error.locate(raw_input)))
+        .collect()
+}
+
+/// The powers of the games added up, the answer of part two
+pub fn sum_powers(games: &[Game]) -> Result<u64, AocError> {
+    games.iter().try_fold(0u64, |sum, game| {
+        sum.checked_add(game.checked_power()?).ok_or_else(|| {
+            AocError::Overflow(format!(
+                "The sum of the powers overflows at game {}",
+                game.id
+            ))
+        })
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+    type Answer = u64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("8"),
+        part_two: Some("2286"),
+    }];
+
+    fn parse(raw_input: &str) -> Result<Vec<Game>, AocError> {
+        Ok(parse_games(raw_input)?)
+    }
+
+    /// Colors other than red, green and blue are an error
+    fn part_one(games: &Vec<Game>) -> Result<u64, AocError> {
+        Ok(check_bag(games, &Bag::puzzle(), Strictness::Strict)?.sum() as u64)
+    }
+
+    /// Colors other than red, green and blue are an error, like in part one
+    fn part_two(games: &Vec<Game>) -> Result<u64, AocError> {
+        check_bag(games, &Bag::puzzle(), Strictness::Strict)?;
+
+        sum_powers(games)
+    }
+
+    /// The grabs that do not fit in the bag, or the power of the minimum cubes of every game
+    fn details(games: &Vec<Game>, part: Part) -> Result<Vec<Detail>, AocError> {
+        if part == Part::One {
+            return Ok(find_violations(games, &Bag::puzzle())
+                .iter()
+                .map(|violation| {
+                    Detail::new(
+                        format!("Game {} grab {}", violation.grab.game, violation.grab.grab),
+                        format!(
+                            "{} {}, {} too many",
+                            violation.shown,
+                            violation.color,
+                            violation.excess()
+                        ),
+                    )
+                })
+                .collect());
+        }
+
+        games
+            .iter()
+            .map(|game| {
+                Ok(Detail::new(
+                    format!("Game {}", game.id),
+                    game.checked_power()?,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(line: &str) -> Game {
+        Game::parse(line).unwrap()
+    }
+
+    fn cubes(red: u32, green: u32, blue: u32) -> Grab {
+        Grab {
+            cubes: [
+                (Color::Red, red),
+                (Color::Green, green),
+                (Color::Blue, blue),
+            ]
+            .into_iter()
+            .filter(|&(_, quantity)| quantity > 0)
+            .collect(),
+        }
+    }
+
+    fn minimum(red: u32, green: u32, blue: u32) -> Grab {
+        Grab {
+            cubes: [
+                (Color::Red, red),
+                (Color::Green, green),
+                (Color::Blue, blue),
+            ]
+            .into(),
+        }
+    }
+
+    #[test]
+    fn can_parse_a_game() {
+        assert_eq!(
+            game("Game 12: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            Game {
+                id: 12,
+                grabs: vec![cubes(4, 0, 3), cubes(1, 2, 6), cubes(0, 2, 0)]
+            }
+        );
+    }
+
+    #[test]
+    fn get_minimum_cubes_test() {
+        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        assert_eq!(game(line).get_minimum_cubes(), minimum(4, 2, 6));
+
+        let line = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        assert_eq!(game(line).get_minimum_cubes(), minimum(1, 3, 4));
+
+        let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        assert_eq!(game(line).get_minimum_cubes(), minimum(20, 13, 6));
+
+        let line = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
+        assert_eq!(game(line).get_minimum_cubes(), minimum(14, 3, 15));
+
+        let line = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(game(line).get_minimum_cubes(), minimum(6, 3, 2));
+    }
+
+    #[test]
+    fn get_power_of_minimum_cubes_test() {
+        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        assert_eq!(game(line).get_power_of_minimum_cubes(), Some(48));
+
+        let line = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        assert_eq!(game(line).get_power_of_minimum_cubes(), Some(12));
+
+        let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        assert_eq!(game(line).get_power_of_minimum_cubes(), Some(1560));
+
+        let line = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
+        assert_eq!(game(line).get_power_of_minimum_cubes(), Some(630));
+
+        let line = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(game(line).get_power_of_minimum_cubes(), Some(36));
+
+        // 4_000_000_000^3 does not fit in a u64
+        let line = "Game 6: 4000000000 red, 4000000000 green, 4000000000 blue";
+        assert_eq!(game(line).get_power_of_minimum_cubes(), None);
+        assert!(matches!(
+            Day02::part_two(&vec![game(line)]),
+            Err(AocError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn is_game_valid_test() {
+        let valid: Vec<bool> = parse_games(EXAMPLE)
+            .unwrap()
+            .iter()
+            .map(|game| game.is_game_valid(&Bag::puzzle()))
+            .collect();
+
+        assert_eq!(valid, [true, true, false, false, true]);
+    }
+
+    #[test]
+    fn reports_malformed_games() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, x green";
+        let error = Day02::parse(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error,
+            "2:17: expected a number, found \"x\"\n    Game 2: 1 blue, x green\n                    ^"
+        );
+
+        let input = "Game 1 3 blue, 4 red";
+        let error = Day02::parse(input).unwrap_err().to_string();
+
+        assert!(error.starts_with("1:21: expected ':', found end of line"));
+
+        let input = "Game 1: 3 blue, 4";
+        let error = Day02::parse(input).unwrap_err().to_string();
+
+        assert!(error.starts_with("1:18: expected a color, found end of line"));
+
+        let input = "Game 1: 3 blue, 4 #ff0000";
+        let error = Day02::parse(input).unwrap_err().to_string();
+
+        assert!(error.starts_with("1:19: expected a color, found \"#ff0000\""));
+
+        let input = "Game 1: 3 red blue";
+        let error = Day02::parse(input).unwrap_err().to_string();
+
+        assert!(error.starts_with("1:15: expected ',' or ';', found \"blue\""));
+
+        let input = "Game 1: 3 blue, ";
+        let error = Day02::parse(input).unwrap_err().to_string();
+
+        assert!(error.starts_with("1:16: expected a number, found end of line"));
+    }
+
+    #[test]
+    fn can_parse_any_color() {
+        let game = game("Game 7: 2 yellow, 1 red, 2 green, 3 blue; 3 yellow");
+
+        assert_eq!(game.grabs[0].get(&Color::Other("yellow".to_string())), 2);
+        assert_eq!(
+            game.get_minimum_cubes().cubes.keys().last(),
+            Some(&Color::Other("yellow".to_string()))
+        );
+        // Only red, green and blue make up the power
+        assert_eq!(game.get_power_of_minimum_cubes(), Some(6));
+    }
+
+    #[test]
+    fn rejects_unknown_colors_in_both_parts() {
+        let games = Day02::parse("Game 1: 3 red, 2 green, 4 blu").unwrap();
+        let expected = "Game 1 shows blu cubes, but the bag red=12,green=13,blue=14 has none";
+
+        assert_eq!(Day02::part_one(&games).unwrap_err().to_string(), expected);
+        assert_eq!(Day02::part_two(&games).unwrap_err().to_string(), expected);
+        assert_eq!(sum_powers(&games).unwrap(), 0);
+    }
+
+    #[test]
+    fn can_get_the_power_of_every_game() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let details = Day02::details(&games, Part::Two).unwrap();
+
+        assert_eq!(details[0], Detail::new("Game 1", 48));
+        assert_eq!(details[4], Detail::new("Game 5", 36));
+
+        let details = Day02::details(&games, Part::One).unwrap();
+
+        assert_eq!(
+            details[0],
+            Detail::new("Game 3 grab 1", "20 red, 8 too many")
+        );
+    }
+}
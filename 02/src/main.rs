@@ -1,244 +1,187 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-
-const MAX_RED_CUBES: u32 = 12;
-const MAX_GREEN_CUBES: u32 = 13;
-const MAX_BLUE_CUBES: u32 = 14;
+use advent_of_code_02::simulation::{exact_log_likelihood, rank_bags, Rng};
+use advent_of_code_02::{
+    check_bag, find_violations, infer_bag, sum_powers, Bag, Day02, Game, Query, Strictness,
+};
+use aoc_common::input::InputSource;
+use aoc_common::solution::day_args;
+use aoc_common::{AocError, OrExit, Solution};
 
 fn main() {
-    let mut total_part_one: u32 = 0;
-    let mut total_part_two: u32 = 0;
-
-    // File hosts.txt must exist in the current path
-    if let Ok(lines) = read_lines("input.txt") {
-        // Consumes the iterator, returns an (Optional) String
-        for line_result in lines {
-            if let Ok(line) = line_result {
-                if is_line_valid(&line) {
-                    total_part_one += get_game_number_from_line(&line);
-                }
-
-                total_part_two += get_power_of_minimum_cubes(&line);
-            }
-        }
+    let args = day_args::<Day02>("Day 2");
+
+    let (options, mut args) = parse_options(args).or_exit();
+    let query = parse_query(&mut args).or_exit();
+    let Options {
+        bags,
+        strictness,
+        samples,
+        seed,
+    } = options;
+    let is_report = args.iter().any(|arg| arg == "--report");
+    let source = InputSource::from_args_in_crate(&args, env!("CARGO_MANIFEST_DIR"));
+    let input = source.read().or_exit();
+    let games = Day02::parse(&input)
+        .map_err(|error| error.in_file(source.to_string()))
+        .or_exit();
+
+    // `query <EXPRESSION>` only answers the query
+    if let Some(query) = query {
+        println!("{}", query.run(&games).or_exit());
+        return;
     }
 
-    println!("Total part 1: {}", total_part_one);
-    println!("Total part 2: {}", total_part_two);
-}
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-fn is_line_valid(line: &str) -> bool {
-    let cube_grabs = get_hash_vector_from_line(line);
-
-    is_game_valid(cube_grabs)
-}
-
-fn get_game_number_from_line(line: &str) -> u32 {
-    let mut split_by_colon = line.split(":").take(1);
-
-    split_by_colon
-        .next()
-        .unwrap()
-        .split(" ")
-        .skip(1)
-        .take(1)
-        .next()
-        .unwrap()
-        .parse()
-        .expect("Game should be an integer")
-}
-
-fn get_hash_vector_from_line(line: &str) -> Vec<HashMap<&str, u32>> {
-    let mut split_by_colon = line.split(":").take(2).skip(1);
-
-    // 14 green, 3 red, 16 blue; 3 blue, 6 green; 12 green, 6 blue, 2 red
-    // [
-    //     {
-    //         green: 14
-    //         red: 3
-    //         blue: 16
-    //     },
-    //     {
-    //         blue: 3,
-    //         green: 6
-    //     },
-    //     {
-    //         green: 12,
-    //         blue: 6,
-    //         red: 2
-    //     }
-    // ]
-    split_by_colon
-        .next()
-        .unwrap()
-        .split(";")
-        .map(|x| x.trim())
-        .map(|x| parse_grab(x))
-        .collect()
-}
+    // Without `--bag` the games are checked against the bag of the puzzle
+    let checked_bags = match bags.is_empty() {
+        true => vec![Bag::puzzle()],
+        false => bags.clone(),
+    };
 
-fn parse_grab(cubes_str: &str) -> HashMap<&str, u32> {
-    let cubes: Vec<&str> = cubes_str.split(",").map(|x| x.trim()).collect();
+    for bag in &checked_bags {
+        let check = check_bag(&games, bag, strictness).or_exit();
+        let possible: Vec<String> = check.possible.iter().map(|id| id.to_string()).collect();
+
+        match bags.is_empty() {
+            true => println!("Total part 1: {}", check.sum()),
+            false => println!(
+                "Total part 1 with {}: {} (games {})",
+                bag,
+                check.sum(),
+                possible.join(", ")
+            ),
+        }
 
-    let mut cube_map = HashMap::new();
+        for (id, color) in &check.unknown_colors {
+            eprintln!(
+                "Game {} shows {} cubes, but the bag {} has none",
+                id, color, bag
+            );
+        }
 
-    for cube_str in cubes {
-        let qty_and_color: Vec<&str> = cube_str.split(" ").map(|x| x.trim()).collect();
-        let quantity: u32 = qty_and_color[0]
-            .parse()
-            .expect("Quantity should be a number");
-        let color = qty_and_color[1];
+        if is_report {
+            print_violations(&games, bag);
+        }
+    }
 
-        cube_map.insert(color, quantity);
+    // `--report` explains the answers with the grabs that do not fit and the smallest bag
+    if is_report {
+        println!("{}", infer_bag(&games));
     }
 
-    cube_map
-}
+    if let Some(samples) = samples {
+        simulate(&games, &bags, samples, seed);
+    }
 
-fn is_game_valid(grabs: Vec<HashMap<&str, u32>>) -> bool {
-    grabs
-        .into_iter()
-        .fold(true, |acc, grab| acc && is_grab_valid(grab))
+    // The colors were checked against the bags above, with `--lenient` too
+    let total_part_two = sum_powers(&games).or_exit();
+    println!("Total part 2: {}", total_part_two);
 }
 
-fn is_grab_valid(grab: HashMap<&str, u32>) -> bool {
-    grab.into_iter().fold(true, |acc, color_quantity_tuple| {
-        acc && is_color_valid(color_quantity_tuple)
-    })
+fn print_violations(games: &[Game], bag: &Bag) {
+    for violation in find_violations(games, bag) {
+        println!("    {}", violation);
+    }
 }
 
-fn is_color_valid((color, quantity): (&str, u32)) -> bool {
-    let result = match color {
-        "red" => MAX_RED_CUBES >= quantity,
-        "green" => MAX_GREEN_CUBES >= quantity,
-        "blue" => MAX_BLUE_CUBES >= quantity,
-        _ => return true,
+/// Rank the bags, or the bag of the puzzle and the smallest bag, by how likely they give the games
+fn simulate(games: &[Game], bags: &[Bag], samples: usize, seed: u64) {
+    let bags = match bags.is_empty() {
+        true => vec![Bag::puzzle(), infer_bag(games).bag],
+        false => bags.to_vec(),
     };
 
-    // println!("Color: {}, qty: {}, result: {}", color, quantity, result);
-
-    result
+    let mut rng = Rng::new(seed);
+
+    // Grabs that are rarer than one in `samples` are never drawn and estimated as 0,
+    // the exact log-likelihood shows the difference
+    println!(
+        "Log-likelihood of the games, {} samples per grab, seed {}:",
+        samples, seed
+    );
+    println!("    {:>10}  {:>10}  bag", "estimated", "exact");
+
+    for ranked in rank_bags(games, &bags, samples, &mut rng) {
+        println!(
+            "    {:>10.2}  {:>10.2}  {}",
+            ranked.log_likelihood,
+            exact_log_likelihood(games, ranked.bag),
+            ranked.bag
+        );
+
+        for (id, likelihood) in &ranked.games {
+            println!("        game {}: {:.4e}", id, likelihood);
+        }
+    }
 }
 
-fn get_power_of_minimum_cubes(line: &str) -> u32 {
-    let minimum_cubes = get_minimum_cubes(line);
-
-    let mut power: u32 = 1;
-
-    for (_, quantity) in minimum_cubes.into_iter() {
-        power = power * quantity;
+/// `query <EXPRESSION>` as the first arguments, removed from the arguments
+fn parse_query(args: &mut Vec<String>) -> Result<Option<Query>, AocError> {
+    if args.first().is_none_or(|arg| arg != "query") {
+        return Ok(None);
     }
 
-    power
-}
-
-fn get_minimum_cubes(line: &str) -> HashMap<&str, u32> {
-    let cube_grabs = get_hash_vector_from_line(line);
+    let expression = args
+        .get(1)
+        .cloned()
+        .ok_or_else(|| AocError::Unsupported("Missing value for query".to_string()))?;
+    args.drain(..2);
 
-    let mut minimum_cubes: HashMap<&str, u32> = HashMap::new();
+    Ok(Some(
+        Query::parse(&expression).map_err(|error| error.locate(&expression))?,
+    ))
+}
 
-    for cube_grab in cube_grabs {
-        for (color, quantity) in cube_grab.into_iter() {
-            let current_minimum = minimum_cubes.get(color).unwrap_or_else(|| &0);
+struct Options {
+    bags: Vec<Bag>,
+    strictness: Strictness,
+    samples: Option<usize>,
+    seed: u64,
+}
 
-            if current_minimum < &quantity {
-                minimum_cubes.insert(color, quantity);
+/// The options of the binary and the other arguments
+///
+/// - `--bag red=12,green=13,blue=14` (repeatable) and `--bags <file>` choose the bags
+/// - `--lenient` ignores the colors that are not in a bag
+/// - `--simulate <samples>` ranks the bags with random grabs, `--seed <n>` seeds them
+fn parse_options(args: Vec<String>) -> Result<(Options, Vec<String>), AocError> {
+    let mut options = Options {
+        bags: vec![],
+        strictness: Strictness::Strict,
+        samples: None,
+        seed: 2023,
+    };
+    let mut rest = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" | "--bags" | "--simulate" | "--seed" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| AocError::Unsupported(format!("Missing value for {}", arg)))?;
+                let invalid =
+                    || AocError::Unsupported(format!("Invalid value for {}: {:?}", arg, value));
+
+                match arg.as_str() {
+                    "--bag" => options
+                        .bags
+                        .push(Bag::parse(&value).map_err(|error| error.locate(&value))?),
+                    "--bags" => options.bags.extend(Bag::load(&value)?),
+                    "--simulate" => {
+                        options.samples = Some(
+                            value
+                                .parse()
+                                .ok()
+                                .filter(|&samples| samples > 0)
+                                .ok_or_else(invalid)?,
+                        )
+                    }
+                    _ => options.seed = value.parse().map_err(|_| invalid())?,
+                }
             }
+            "--lenient" => options.strictness = Strictness::Lenient,
+            _ => rest.push(arg),
         }
     }
 
-    minimum_cubes
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn get_minimum_cubes_test() {
-        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
-        let minimums = get_minimum_cubes(line);
-
-        assert_eq!(minimums.contains_key("blue"), true);
-        assert_eq!(minimums.contains_key("red"), true);
-        assert_eq!(minimums.contains_key("green"), true);
-
-        assert_eq!(minimums.get("blue").unwrap().to_owned(), 6);
-        assert_eq!(minimums.get("red").unwrap().to_owned(), 4);
-        assert_eq!(minimums.get("green").unwrap().to_owned(), 2);
-
-        let line = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
-        let minimums = get_minimum_cubes(line);
-
-        assert_eq!(minimums.contains_key("blue"), true);
-        assert_eq!(minimums.contains_key("red"), true);
-        assert_eq!(minimums.contains_key("green"), true);
-
-        assert_eq!(minimums.get("blue").unwrap().to_owned(), 4);
-        assert_eq!(minimums.get("red").unwrap().to_owned(), 1);
-        assert_eq!(minimums.get("green").unwrap().to_owned(), 3);
-
-        let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
-        let minimums = get_minimum_cubes(line);
-
-        assert_eq!(minimums.contains_key("blue"), true);
-        assert_eq!(minimums.contains_key("red"), true);
-        assert_eq!(minimums.contains_key("green"), true);
-
-        assert_eq!(minimums.get("blue").unwrap().to_owned(), 6);
-        assert_eq!(minimums.get("red").unwrap().to_owned(), 20);
-        assert_eq!(minimums.get("green").unwrap().to_owned(), 13);
-
-        let line = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
-        let minimums = get_minimum_cubes(line);
-
-        assert_eq!(minimums.contains_key("blue"), true);
-        assert_eq!(minimums.contains_key("red"), true);
-        assert_eq!(minimums.contains_key("green"), true);
-
-        assert_eq!(minimums.get("blue").unwrap().to_owned(), 15);
-        assert_eq!(minimums.get("red").unwrap().to_owned(), 14);
-        assert_eq!(minimums.get("green").unwrap().to_owned(), 3);
-
-        let line = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        let minimums = get_minimum_cubes(line);
-
-        assert_eq!(minimums.contains_key("blue"), true);
-        assert_eq!(minimums.contains_key("red"), true);
-        assert_eq!(minimums.contains_key("green"), true);
-
-        assert_eq!(minimums.get("blue").unwrap().to_owned(), 2);
-        assert_eq!(minimums.get("red").unwrap().to_owned(), 6);
-        assert_eq!(minimums.get("green").unwrap().to_owned(), 3);
-    }
-
-    #[test]
-    fn get_power_of_minimum_cubes_test() {
-        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
-        assert_eq!(get_power_of_minimum_cubes(line), 48);
-
-        let line = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
-        assert_eq!(get_power_of_minimum_cubes(line), 12);
-
-        let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
-        assert_eq!(get_power_of_minimum_cubes(line), 1560);
-
-        let line = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
-        assert_eq!(get_power_of_minimum_cubes(line), 630);
-
-        let line = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        assert_eq!(get_power_of_minimum_cubes(line), 36);
-    }
+    Ok((options, rest))
 }
@@ -0,0 +1,408 @@
+use std::fmt;
+
+use aoc_common::parse::parse_number;
+use aoc_common::{AocError, ParseError};
+
+use crate::{Color, Game, Grab};
+
+/// A filter over the games, like `max(red) > 10 and any(grab, blue == 0)`
+///
+/// ```text
+/// condition  = and ("or" and)*
+/// and        = unary ("and" unary)*
+/// unary      = "not" unary | "(" condition ")" | ("any" | "all") "(" "grab" "," condition ")"
+///            | value ("==" | "!=" | "<" | "<=" | ">" | ">=") value
+/// value      = number | "id" | "power" | "grabs" | ("max" | "min" | "sum") "(" color ")"
+///            | color, the cubes of a color in the grab of `any` or `all`
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    condition: Condition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Compare(Value, Comparison, Value),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    /// `any(grab, ...)` when the flag is false, `all(grab, ...)` when it is true
+    Grabs {
+        all: bool,
+        condition: Box<Condition>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Number(u64),
+    Id,
+    /// The power of the minimum cubes
+    Power,
+    /// The number of grabs
+    Grabs,
+    Max(Color),
+    Min(Color),
+    Sum(Color),
+    /// The cubes of a color in the current grab
+    Cubes(Color),
+}
+
+const KEYWORDS: [&str; 12] = [
+    "and", "or", "not", "any", "all", "grab", "id", "power", "grabs", "max", "min", "sum",
+];
+
+impl Query {
+    pub fn parse(str: &str) -> Result<Query, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(str)?,
+            position: 0,
+            end: &str[str.len()..],
+        };
+        let condition = parser.condition(false)?;
+
+        match parser.peek() {
+            "" => Ok(Query { condition }),
+            token => Err(ParseError::new(
+                token,
+                "\"and\", \"or\" or the end of the query",
+            )),
+        }
+    }
+
+    pub fn matches(&self, game: &Game) -> bool {
+        self.condition.matches(game, None)
+    }
+
+    /// The games that match, with their number, the sum of their ids and of their powers
+    pub fn run(&self, games: &[Game]) -> Result<QueryResult, AocError> {
+        let mut result = QueryResult::default();
+
+        for game in games.iter().filter(|game| self.matches(game)) {
+            result.ids.push(game.id);
+            result.id_sum = result.id_sum.checked_add(game.id as u64).ok_or_else(|| {
+                AocError::Overflow(format!("The sum of the ids overflows at game {}", game.id))
+            })?;
+            result.power_sum = result
+                .power_sum
+                .checked_add(game.checked_power()?)
+                .ok_or_else(|| {
+                    AocError::Overflow(format!(
+                        "The sum of the powers overflows at game {}",
+                        game.id
+                    ))
+                })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// The ids of the games that match a query and the aggregates over them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryResult {
+    pub ids: Vec<u32>,
+    pub id_sum: u64,
+    pub power_sum: u64,
+}
+
+impl QueryResult {
+    pub fn count(&self) -> usize {
+        self.ids.len()
+    }
+}
+
+impl fmt::Display for QueryResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ids: Vec<String> = self.ids.iter().map(u32::to_string).collect();
+
+        writeln!(f, "Games: {}", ids.join(", "))?;
+        writeln!(f, "Count: {}", self.count())?;
+        writeln!(f, "Sum of ids: {}", self.id_sum)?;
+        write!(f, "Sum of powers: {}", self.power_sum)
+    }
+}
+
+impl Condition {
+    fn matches(&self, game: &Game, grab: Option<&Grab>) -> bool {
+        match self {
+            Condition::Compare(left, comparison, right) => {
+                let (left, right) = (left.eval(game, grab), right.eval(game, grab));
+
+                match comparison {
+                    Comparison::Equal => left == right,
+                    Comparison::NotEqual => left != right,
+                    Comparison::Less => left < right,
+                    Comparison::LessOrEqual => left <= right,
+                    Comparison::Greater => left > right,
+                    Comparison::GreaterOrEqual => left >= right,
+                }
+            }
+            Condition::And(left, right) => left.matches(game, grab) && right.matches(game, grab),
+            Condition::Or(left, right) => left.matches(game, grab) || right.matches(game, grab),
+            Condition::Not(condition) => !condition.matches(game, grab),
+            Condition::Grabs {
+                all: true,
+                condition,
+            } => game
+                .grabs
+                .iter()
+                .all(|grab| condition.matches(game, Some(grab))),
+            Condition::Grabs {
+                all: false,
+                condition,
+            } => game
+                .grabs
+                .iter()
+                .any(|grab| condition.matches(game, Some(grab))),
+        }
+    }
+}
+
+impl Value {
+    fn eval(&self, game: &Game, grab: Option<&Grab>) -> u64 {
+        match self {
+            Value::Number(number) => *number,
+            Value::Id => game.id as u64,
+            // A power that overflows is larger than any number
+            Value::Power => game.get_power_of_minimum_cubes().unwrap_or(u64::MAX),
+            Value::Grabs => game.grabs.len() as u64,
+            Value::Max(color) => cubes(game, color).max().unwrap_or(0),
+            Value::Min(color) => cubes(game, color).min().unwrap_or(0),
+            Value::Sum(color) => cubes(game, color).sum(),
+            // The parser only accepts a color inside `any` or `all`
+            Value::Cubes(color) => grab.map_or(0, |grab| grab.get(color) as u64),
+        }
+    }
+}
+
+/// The cubes of a color in every grab of the game
+fn cubes<'a>(game: &'a Game, color: &'a Color) -> impl Iterator<Item = u64> + 'a {
+    game.grabs.iter().map(move |grab| grab.get(color) as u64)
+}
+
+/// Words, numbers, operators, parentheses and commas, every token is a slice of the query
+fn tokenize(str: &str) -> Result<Vec<&str>, ParseError> {
+    let mut tokens = vec![];
+    let mut rest = str.trim_start();
+
+    while let Some(ch) = rest.chars().next() {
+        let len = if ch.is_alphanumeric() || ch == '_' {
+            rest.find(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .unwrap_or(rest.len())
+        } else if ["==", "!=", "<=", ">="]
+            .iter()
+            .any(|op| rest.starts_with(op))
+        {
+            2
+        } else if "<>(),".contains(ch) {
+            1
+        } else {
+            return Err(ParseError::new(
+                &rest[..ch.len_utf8()],
+                "a word, a number, a comparison, a parenthesis or a comma",
+            ));
+        };
+
+        tokens.push(&rest[..len]);
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    position: usize,
+    /// The empty slice at the end of the query, the token of a missing token
+    end: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> &'a str {
+        self.tokens.get(self.position).copied().unwrap_or(self.end)
+    }
+
+    fn next(&mut self) -> &'a str {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        match self.next() {
+            token if token == expected => Ok(()),
+            token => Err(ParseError::new(token, format!("{:?}", expected))),
+        }
+    }
+
+    /// `in_grab` is true inside `any` and `all`, where colors stand for the cubes of the grab
+    fn condition(&mut self, in_grab: bool) -> Result<Condition, ParseError> {
+        let mut condition = self.and(in_grab)?;
+
+        while self.peek() == "or" {
+            self.next();
+            condition = Condition::Or(Box::new(condition), Box::new(self.and(in_grab)?));
+        }
+
+        Ok(condition)
+    }
+
+    fn and(&mut self, in_grab: bool) -> Result<Condition, ParseError> {
+        let mut condition = self.unary(in_grab)?;
+
+        while self.peek() == "and" {
+            self.next();
+            condition = Condition::And(Box::new(condition), Box::new(self.unary(in_grab)?));
+        }
+
+        Ok(condition)
+    }
+
+    fn unary(&mut self, in_grab: bool) -> Result<Condition, ParseError> {
+        match self.peek() {
+            "not" => {
+                self.next();
+                Ok(Condition::Not(Box::new(self.unary(in_grab)?)))
+            }
+            "(" => {
+                self.next();
+                let condition = self.condition(in_grab)?;
+                self.expect(")")?;
+                Ok(condition)
+            }
+            token @ ("any" | "all") => {
+                if in_grab {
+                    return Err(ParseError::new(token, "a condition on the grab"));
+                }
+
+                self.next();
+                self.expect("(")?;
+                self.expect("grab")?;
+                self.expect(",")?;
+                let condition = self.condition(true)?;
+                self.expect(")")?;
+
+                Ok(Condition::Grabs {
+                    all: token == "all",
+                    condition: Box::new(condition),
+                })
+            }
+            _ => {
+                let left = self.value(in_grab)?;
+                let comparison = match self.next() {
+                    "==" => Comparison::Equal,
+                    "!=" => Comparison::NotEqual,
+                    "<" => Comparison::Less,
+                    "<=" => Comparison::LessOrEqual,
+                    ">" => Comparison::Greater,
+                    ">=" => Comparison::GreaterOrEqual,
+                    token => return Err(ParseError::new(token, "a comparison")),
+                };
+
+                Ok(Condition::Compare(left, comparison, self.value(in_grab)?))
+            }
+        }
+    }
+
+    fn value(&mut self, in_grab: bool) -> Result<Value, ParseError> {
+        let token = self.next();
+
+        match token {
+            "id" => Ok(Value::Id),
+            "power" => Ok(Value::Power),
+            "grabs" => Ok(Value::Grabs),
+            "max" | "min" | "sum" => {
+                self.expect("(")?;
+                let color = self.color()?;
+                self.expect(")")?;
+
+                Ok(match token {
+                    "max" => Value::Max(color),
+                    "min" => Value::Min(color),
+                    _ => Value::Sum(color),
+                })
+            }
+            _ if token.starts_with(|ch: char| ch.is_ascii_digit()) => {
+                Ok(Value::Number(parse_number(token)?))
+            }
+            _ if in_grab && !KEYWORDS.contains(&token) => Ok(Value::Cubes(Color::parse(token)?)),
+            _ if !KEYWORDS.contains(&token) && Color::parse(token).is_ok() => Err(ParseError::new(
+                token,
+                "an aggregate like max(red), a color is only a value inside any(grab, ...)",
+            )),
+            _ => Err(ParseError::new(
+                token,
+                "a number, id, power, grabs or an aggregate like max(red)",
+            )),
+        }
+    }
+
+    fn color(&mut self) -> Result<Color, ParseError> {
+        match self.next() {
+            token if KEYWORDS.contains(&token) => Err(ParseError::new(token, "a color")),
+            token => Color::parse(token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_games, EXAMPLE};
+
+    fn ids(query: &str) -> Vec<u32> {
+        let games = parse_games(EXAMPLE).unwrap();
+        Query::parse(query).unwrap().run(&games).unwrap().ids
+    }
+
+    #[test]
+    fn can_filter_games() {
+        assert_eq!(ids("max(red) > 10"), [3, 4]);
+        assert_eq!(ids("max(red) > 10 and any(grab, blue == 0)"), [3, 4]);
+        assert_eq!(ids("all(grab, blue > 0)"), [2, 5]);
+        assert_eq!(ids("power < 100"), [1, 2, 5]);
+        assert_eq!(ids("not (id == 1 or id >= 3)"), [2]);
+        assert_eq!(ids("sum(green) == 4 or grabs == 2"), [1, 5]);
+        assert_eq!(ids("min(blue) == 0 and 1 == 1"), [1, 3, 4]);
+        assert_eq!(ids("max(yellow) > 0"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn can_aggregate_the_matches() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let result = Query::parse("id > 0").unwrap().run(&games).unwrap();
+
+        assert_eq!(result.count(), 5);
+        assert_eq!(result.id_sum, 15);
+        assert_eq!(result.power_sum, 2286);
+    }
+
+    #[test]
+    fn reports_malformed_queries() {
+        let error = |query: &str| {
+            let error = Query::parse(query).unwrap_err().locate(query);
+            (error.column, error.found)
+        };
+
+        assert_eq!(error("max(red) >"), (11, "".to_string()));
+        assert_eq!(error("red > 1"), (1, "red".to_string()));
+        assert_eq!(error("power < 100 power"), (13, "power".to_string()));
+        assert_eq!(
+            error("any(grab, all(grab, red > 1))"),
+            (11, "all".to_string())
+        );
+        assert_eq!(error("id = 1"), (4, "=".to_string()));
+        assert_eq!(error("max(and) > 1"), (5, "and".to_string()));
+    }
+}
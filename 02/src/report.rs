@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Bag, Color, Game};
+
+/// A grab of a game, both counted from 1 like in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GrabRef {
+    pub game: u32,
+    pub grab: usize,
+}
+
+impl fmt::Display for GrabRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "game {} grab {}", self.game, self.grab)
+    }
+}
+
+/// The fewest cubes of a color the bag must hold, and the grabs that show that many
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraint {
+    pub color: Color,
+    pub minimum: u32,
+    /// Empty when no game shows the color
+    pub grabs: Vec<GrabRef>,
+}
+
+/// The smallest bag that makes every game possible, with the reason for every color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BagReport {
+    pub bag: Bag,
+    pub constraints: Vec<Constraint>,
+}
+
+impl fmt::Display for BagReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Smallest bag: {}", self.bag)?;
+
+        for constraint in &self.constraints {
+            let grabs: Vec<String> = constraint.grabs.iter().map(GrabRef::to_string).collect();
+
+            match grabs.is_empty() {
+                true => write!(f, "\n    {} 0: no game shows it", constraint.color)?,
+                false => write!(
+                    f,
+                    "\n    {} {}: {}",
+                    constraint.color,
+                    constraint.minimum,
+                    grabs.join(", ")
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A grab that shows more cubes of a color than the bag holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub grab: GrabRef,
+    pub color: Color,
+    pub shown: u32,
+    pub limit: u32,
+}
+
+impl Violation {
+    /// How many cubes too many the grab shows
+    pub fn excess(&self) -> u32 {
+        self.shown - self.limit
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} shows {} {}, {} more than the {} in the bag",
+            self.grab,
+            self.shown,
+            self.color,
+            self.excess(),
+            self.limit
+        )
+    }
+}
+
+/// The smallest bag for all games, red, green and blue are always included
+pub fn infer_bag(games: &[Game]) -> BagReport {
+    let mut constraints: BTreeMap<Color, (u32, Vec<GrabRef>)> = Color::BASIC
+        .into_iter()
+        .map(|color| (color, (0, vec![])))
+        .collect();
+
+    for game in games {
+        for (index, grab) in game.grabs.iter().enumerate() {
+            let grab_ref = GrabRef {
+                game: game.id,
+                grab: index + 1,
+            };
+
+            for (color, &quantity) in &grab.cubes {
+                let (minimum, grabs) = constraints.entry(color.clone()).or_default();
+
+                if quantity > *minimum {
+                    *minimum = quantity;
+                    grabs.clear();
+                }
+
+                if quantity == *minimum && quantity > 0 {
+                    grabs.push(grab_ref);
+                }
+            }
+        }
+    }
+
+    BagReport {
+        bag: constraints
+            .iter()
+            .map(|(color, (minimum, _))| (color.clone(), *minimum))
+            .collect(),
+        constraints: constraints
+            .into_iter()
+            .map(|(color, (minimum, grabs))| Constraint {
+                color,
+                minimum,
+                grabs,
+            })
+            .collect(),
+    }
+}
+
+/// Every color of every grab that does not fit in the bag, colors that are not in the bag are ignored
+pub fn find_violations(games: &[Game], bag: &Bag) -> Vec<Violation> {
+    let mut violations = vec![];
+
+    for game in games {
+        for (index, grab) in game.grabs.iter().enumerate() {
+            for (color, &shown) in &grab.cubes {
+                match bag.get(color) {
+                    Some(limit) if shown > limit => violations.push(Violation {
+                        grab: GrabRef {
+                            game: game.id,
+                            grab: index + 1,
+                        },
+                        color: color.clone(),
+                        shown,
+                        limit,
+                    }),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_games, EXAMPLE};
+
+    #[test]
+    fn can_infer_the_smallest_bag() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let report = infer_bag(&games);
+
+        assert_eq!(report.bag, Bag::parse("red=20,green=13,blue=15").unwrap());
+        assert_eq!(
+            report.to_string(),
+            "Smallest bag: red=20,green=13,blue=15\n    red 20: game 3 grab 1\n    green 13: game 3 grab 2\n    blue 15: game 4 grab 3"
+        );
+
+        let games = parse_games("Game 1: 2 red; 2 red, 1 pink\nGame 2: 2 red").unwrap();
+        let report = infer_bag(&games);
+
+        assert_eq!(report.constraints[0].grabs.len(), 3);
+        assert!(report
+            .to_string()
+            .contains("\n    green 0: no game shows it"));
+        assert!(report.to_string().ends_with("\n    pink 1: game 1 grab 2"));
+    }
+
+    #[test]
+    fn can_find_the_violations() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let violations = find_violations(&games, &Bag::puzzle());
+
+        let found: Vec<String> = violations.iter().map(Violation::to_string).collect();
+        assert_eq!(
+            found,
+            [
+                "game 3 grab 1 shows 20 red, 8 more than the 12 in the bag",
+                "game 4 grab 3 shows 14 red, 2 more than the 12 in the bag",
+                "game 4 grab 3 shows 15 blue, 1 more than the 14 in the bag"
+            ]
+        );
+
+        let report = infer_bag(&games);
+        assert!(find_violations(&games, &report.bag).is_empty());
+    }
+}
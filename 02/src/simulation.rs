@@ -0,0 +1,288 @@
+//! Estimate how likely the recorded games are for a bag, by drawing random grabs from it
+//!
+//! Every grab draws as many cubes as the recorded grab shows, without replacement, and the
+//! cubes go back into the bag before the next grab. The likelihood of a game is the product
+//! of how often the draws of every grab show exactly the recorded cubes, so the likelihoods
+//! of the same game can be compared across bags.
+//!
+//! Grabs that are less likely than about one in `samples` are estimated as 0, like most grabs
+//! of the puzzle, so use it on bags that are close to the games or with many samples.
+
+use crate::{Bag, Color, Game, Grab};
+
+/// SplitMix64, a small generator that is fully determined by its seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// A uniform number in `0..n`, without the bias of a plain modulo, `n` must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        let threshold = n.wrapping_neg() % n;
+
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(n);
+
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+}
+
+/// The cubes of the bag and of the grab as counts in the same order, `None` when the
+/// grab shows a color that is not in the bag
+fn counts(bag: &Bag, grab: &Grab) -> Option<(Vec<u64>, Vec<u64>)> {
+    let colors: Vec<(&Color, u32)> = bag.iter().collect();
+
+    if grab
+        .cubes
+        .iter()
+        .any(|(color, &quantity)| quantity > 0 && bag.get(color).is_none())
+    {
+        return None;
+    }
+
+    Some((
+        colors
+            .iter()
+            .map(|&(_, quantity)| u64::from(quantity))
+            .collect(),
+        colors
+            .iter()
+            .map(|&(color, _)| u64::from(grab.get(color)))
+            .collect(),
+    ))
+}
+
+/// The share of `samples` random grabs that show exactly the cubes of the grab
+pub fn estimate_grab(bag: &Bag, grab: &Grab, samples: usize, rng: &mut Rng) -> f64 {
+    let Some((bag_counts, grab_counts)) = counts(bag, grab) else {
+        return 0.0;
+    };
+
+    let total: u64 = bag_counts.iter().sum();
+    let size: u64 = grab_counts.iter().sum();
+
+    if size > total || samples == 0 {
+        return 0.0;
+    }
+
+    let mut remaining = bag_counts.clone();
+    let mut drawn = vec![0; bag_counts.len()];
+    let mut hits = 0;
+
+    for _ in 0..samples {
+        remaining.copy_from_slice(&bag_counts);
+        drawn.fill(0);
+
+        for left in (total - size + 1..=total).rev() {
+            let mut cube = rng.below(left);
+            let color = remaining
+                .iter()
+                .position(|&count| {
+                    if cube < count {
+                        true
+                    } else {
+                        cube -= count;
+                        false
+                    }
+                })
+                .expect("The drawn cube should be in the bag");
+
+            remaining[color] -= 1;
+            drawn[color] += 1;
+        }
+
+        if drawn == grab_counts {
+            hits += 1;
+        }
+    }
+
+    hits as f64 / samples as f64
+}
+
+/// The estimated likelihood of the game, `samples` random grabs are drawn for every grab
+pub fn estimate_game(bag: &Bag, game: &Game, samples: usize, rng: &mut Rng) -> f64 {
+    game.grabs
+        .iter()
+        .map(|grab| estimate_grab(bag, grab, samples, rng))
+        .product()
+}
+
+/// The exact probability to draw the grab, from the multivariate hypergeometric distribution
+pub fn grab_probability(bag: &Bag, grab: &Grab) -> f64 {
+    let Some((bag_counts, grab_counts)) = counts(bag, grab) else {
+        return 0.0;
+    };
+
+    let total: u64 = bag_counts.iter().sum();
+    let size: u64 = grab_counts.iter().sum();
+
+    if size > total
+        || bag_counts
+            .iter()
+            .zip(&grab_counts)
+            .any(|(in_bag, in_grab)| in_grab > in_bag)
+    {
+        return 0.0;
+    }
+
+    let ways: f64 = bag_counts
+        .iter()
+        .zip(&grab_counts)
+        .map(|(&n, &k)| ln_choose(n, k))
+        .sum();
+
+    (ways - ln_choose(total, size)).exp()
+}
+
+/// The natural logarithm of n choose k, `k` must not be larger than `n`
+fn ln_choose(n: u64, k: u64) -> f64 {
+    (0..k)
+        .map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln())
+        .sum()
+}
+
+/// The exact log-likelihood of all games, to compare the estimates of `rank_bags` with
+pub fn exact_log_likelihood(games: &[Game], bag: &Bag) -> f64 {
+    games
+        .iter()
+        .flat_map(|game| &game.grabs)
+        .map(|grab| grab_probability(bag, grab).ln())
+        .sum()
+}
+
+/// How likely a bag gives the games, as estimated by `rank_bags`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BagLikelihood<'a> {
+    pub bag: &'a Bag,
+    /// The log-likelihood of all games, minus infinity when the bag cannot give one of them
+    pub log_likelihood: f64,
+    /// The id and the estimated likelihood of every game
+    pub games: Vec<(u32, f64)>,
+}
+
+/// The bags ordered from the most to the least likely
+pub fn rank_bags<'a>(
+    games: &[Game],
+    bags: &'a [Bag],
+    samples: usize,
+    rng: &mut Rng,
+) -> Vec<BagLikelihood<'a>> {
+    let mut ranking: Vec<BagLikelihood> = bags
+        .iter()
+        .map(|bag| {
+            let games: Vec<(u32, f64)> = games
+                .iter()
+                .map(|game| (game.id, estimate_game(bag, game, samples, rng)))
+                .collect();
+
+            BagLikelihood {
+                bag,
+                log_likelihood: games.iter().map(|(_, likelihood)| likelihood.ln()).sum(),
+                games,
+            }
+        })
+        .collect();
+
+    ranking.sort_by(|a, b| b.log_likelihood.total_cmp(&a.log_likelihood));
+    ranking
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_games;
+
+    #[test]
+    fn can_repeat_a_seed() {
+        let first: Vec<u64> = (0..5)
+            .map({
+                let mut rng = Rng::new(7);
+                move |_| rng.below(10)
+            })
+            .collect();
+        let second: Vec<u64> = (0..5)
+            .map({
+                let mut rng = Rng::new(7);
+                move |_| rng.below(10)
+            })
+            .collect();
+
+        assert_eq!(first, second);
+        assert!(first.iter().all(|&x| x < 10));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn estimates_are_close_to_the_exact_probability() {
+        let bag = Bag::parse("red=5,green=3,blue=2").unwrap();
+        let grab = Grab::parse("2 red, 1 blue").unwrap();
+
+        // 10 * 2 / 120
+        assert!((grab_probability(&bag, &grab) - 1.0 / 6.0).abs() < 1e-9);
+
+        let estimate = estimate_grab(&bag, &grab, 20_000, &mut Rng::new(2023));
+        assert!((estimate - 1.0 / 6.0).abs() < 0.01, "{}", estimate);
+
+        assert_eq!(
+            estimate_grab(&bag, &Grab::parse("1 pink").unwrap(), 10, &mut Rng::new(1)),
+            0.0
+        );
+        assert_eq!(grab_probability(&bag, &Grab::parse("3 blue").unwrap()), 0.0);
+    }
+
+    #[test]
+    fn can_rank_bags() {
+        let games =
+            parse_games("Game 1: 2 red; 1 red, 1 green; 2 red\nGame 2: 1 red, 1 blue; 2 red")
+                .unwrap();
+        let bags = [
+            Bag::parse("red=1,green=2,blue=3").unwrap(),
+            Bag::parse("red=4,green=1,blue=1").unwrap(),
+            Bag::parse("red=2,green=2,blue=2").unwrap(),
+        ];
+
+        let ranking = rank_bags(&games, &bags, 5_000, &mut Rng::new(2023));
+
+        assert_eq!(ranking[0].bag, &bags[1]);
+        assert_eq!(ranking[1].bag, &bags[2]);
+        // One red cube cannot give a grab of two
+        assert_eq!(ranking[2].bag, &bags[0]);
+        assert_eq!(ranking[2].log_likelihood, f64::NEG_INFINITY);
+        assert_eq!(ranking[2].games, [(1, 0.0), (2, 0.0)]);
+
+        assert!(ranking[0].log_likelihood > ranking[1].log_likelihood);
+        assert!((ranking[0].log_likelihood - exact_log_likelihood(&games, &bags[1])).abs() < 0.2);
+
+        // The log-likelihood is made of the estimates of the games
+        let product: f64 = ranking[0]
+            .games
+            .iter()
+            .map(|(_, likelihood)| likelihood)
+            .product();
+        assert!((ranking[0].log_likelihood - product.ln()).abs() < 1e-9);
+
+        // The same seed gives the same estimates
+        assert_eq!(
+            ranking,
+            rank_bags(&games, &bags, 5_000, &mut Rng::new(2023))
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use aoc_common::{debug, trace, AocError, Example, Grid, ParseError, Point, Solution};
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+mod regression;
+mod rules;
+mod symbols;
+
+pub use rules::Rules;
+pub use symbols::{Symbol, SymbolIndex};
+
+#[derive(Debug)]
+struct Cell {
+    x: usize,
+    y: usize,
+    ch: char,
+}
+
+#[derive(Debug)]
+struct MachineNumber {
+    cells: Vec<Cell>,
+}
+
+impl MachineNumber {
+    fn get_value(&self) -> u64 {
+        let chars: Vec<String> = self.cells.iter().map(|x| x.ch.to_string()).collect();
+        chars.join("").parse().expect("Value should be a number")
+    }
+
+    /// The cells around the bounding box of the number, each once, so a symbol next to
+    /// several digits is only next to the number once. Without `diagonal` the corners
+    /// of the box are left out.
+    fn get_adjacent_cells(&self, board: &Grid<char>, diagonal: bool) -> BTreeSet<Point> {
+        let (first, last) = (&self.cells[0], &self.cells[self.cells.len() - 1]);
+        let (y, digits) = (first.y, first.x..=last.x);
+
+        (y.saturating_sub(1)..=y + 1)
+            .flat_map(|row| (first.x.saturating_sub(1)..=last.x + 1).map(move |x| (x, row)))
+            .filter(|&(x, row)| {
+                let is_corner = row != y && !digits.contains(&x);
+
+                board.contains((x, row))
+                    && !(row == y && digits.contains(&x))
+                    && (diagonal || !is_corner)
+            })
+            .collect()
+    }
+
+    fn has_adjacent_symbol(&self, board: &Grid<char>, rules: &Rules) -> bool {
+        self.get_adjacent_cells(board, rules.diagonal)
+            .into_iter()
+            .any(|point| rules.is_symbol(board[point]))
+    }
+}
+
+/// Every row must be as wide as the first one
+pub fn parse_input(result: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(result)
+}
+
+fn get_numbers(board: &Grid<char>) -> Vec<MachineNumber> {
+    let mut numbers: Vec<MachineNumber> = vec![];
+
+    for (y, line) in board.rows().enumerate() {
+        let mut machine_number = MachineNumber { cells: vec![] };
+
+        for (x, ch) in line.iter().enumerate() {
+            trace!("x:{}, y:{}, char:{}", x, y, ch);
+
+            if ch.is_ascii_digit() {
+                let cell = Cell { ch: *ch, x, y };
+                machine_number.cells.push(cell)
+            } else {
+                if !machine_number.cells.is_empty() {
+                    numbers.push(machine_number);
+                    machine_number = MachineNumber { cells: vec![] }
+                }
+            }
+        }
+
+        if !machine_number.cells.is_empty() {
+            numbers.push(machine_number);
+        }
+    }
+
+    numbers
+}
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Grid<char>;
+    type Answer = u64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("4361"),
+        part_two: Some("467835"),
+    }];
+
+    fn parse(raw_input: &str) -> Result<Grid<char>, AocError> {
+        Ok(parse_input(raw_input).map_err(|error| error.locate(raw_input))?)
+    }
+
+    fn part_one(board: &Grid<char>) -> Result<u64, AocError> {
+        Ok(part_one(board))
+    }
+
+    fn part_two(board: &Grid<char>) -> Result<u64, AocError> {
+        Ok(part_two(board))
+    }
+}
+
+fn part_one(board: &Grid<char>) -> u64 {
+    sum_part_numbers(board, &Rules::default())
+}
+
+fn part_two(board: &Grid<char>) -> u64 {
+    sum_gear_ratios(board, &Rules::default())
+}
+
+/// The numbers next to a symbol added up
+pub fn sum_part_numbers(board: &Grid<char>, rules: &Rules) -> u64 {
+    let machine_numbers = get_numbers(board);
+
+    let numbers: Vec<u64> = machine_numbers
+        .iter()
+        .filter(|x| x.has_adjacent_symbol(board, rules))
+        .map(|x| x.get_value())
+        .collect();
+
+    numbers.iter().sum()
+}
+
+/// The parts of every gear multiplied together and added up, see `Rules::gear`
+pub fn sum_gear_ratios(board: &Grid<char>, rules: &Rules) -> u64 {
+    let gears = SymbolIndex::with_rules(board, rules)
+        .query(rules.gear, rules.gear_parts..=rules.gear_parts);
+
+    debug!(
+        "gears machine numbers: {:?}",
+        gears
+            .iter()
+            .map(|gear| &gear.parts)
+            .collect::<Vec<&Vec<u64>>>()
+    );
+
+    gears
+        .iter()
+        .map(|gear| gear.parts.iter().product::<u64>())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_input() {
+        let board_string = r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        "#
+        .trim()
+        .to_string();
+
+        let parsed_input = parse_input(&board_string).unwrap();
+
+        let test_board: Vec<Vec<char>> = vec![
+            vec!['4', '6', '7', '.', '.', '1', '1', '4', '.', '.'],
+            vec!['.', '.', '.', '*', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '3', '5', '.', '.', '6', '3', '3', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '#', '.', '.', '.'],
+            vec!['6', '1', '7', '*', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '+', '.', '5', '8', '.'],
+            vec!['.', '.', '5', '9', '2', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '7', '5', '5', '.'],
+            vec!['.', '.', '.', '$', '.', '*', '.', '.', '.', '.'],
+            vec!['.', '6', '6', '4', '.', '5', '9', '8', '.', '.'],
+        ];
+
+        assert_eq!(
+            parsed_input
+                .rows()
+                .map(<[char]>::to_vec)
+                .collect::<Vec<_>>(),
+            test_board
+        );
+    }
+
+    #[test]
+    fn can_get_adjacent_cells() {
+        let board = parse_input(EXAMPLE).unwrap();
+        let numbers = get_numbers(&board);
+
+        // 467 in the top left corner
+        assert_eq!(
+            numbers[0].get_adjacent_cells(&board, true),
+            BTreeSet::from([(3, 0), (0, 1), (1, 1), (2, 1), (3, 1)])
+        );
+        // 633 in the middle
+        assert_eq!(numbers[3].get_adjacent_cells(&board, true).len(), 12);
+        // Without the four corners
+        assert_eq!(numbers[3].get_adjacent_cells(&board, false).len(), 8);
+    }
+
+    #[test]
+    fn can_get_numbers() {
+        let board_string = r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        "#
+        .trim()
+        .to_string();
+
+        let board = parse_input(&board_string).unwrap();
+        let machine_numbers = get_numbers(&board);
+
+        assert_eq!(machine_numbers.len(), 10);
+
+        let numbers: Vec<u64> = machine_numbers.iter().map(|x| x.get_value()).collect();
+
+        let valid_numbers: Vec<u64> = machine_numbers
+            .iter()
+            .filter(|x| x.has_adjacent_symbol(&board, &Rules::default()))
+            .map(|x| x.get_value())
+            .collect();
+
+        assert_eq!(
+            numbers,
+            vec![467, 114, 35, 633, 617, 58, 592, 755, 664, 598]
+        );
+
+        assert_eq!(valid_numbers, vec![467, 35, 633, 617, 592, 755, 664, 598]);
+    }
+
+    #[test]
+    fn can_solve_part_one() {
+        let board_string = r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        "#
+        .trim()
+        .to_string();
+
+        let result_part_one = part_one(&parse_input(&board_string).unwrap());
+
+        assert_eq!(result_part_one, 4361);
+    }
+
+    #[test]
+    fn can_solve_part_two() {
+        let board_string = r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+        "#
+        .trim()
+        .to_string();
+
+        let result_part_two = part_two(&parse_input(&board_string).unwrap());
+
+        assert_eq!(result_part_two, 467835);
+    }
+
+    #[test]
+    fn can_check_is_symbol() {
+        let is_symbol = |ch| Rules::default().is_symbol(ch);
+
+        assert!(is_symbol('*'));
+        assert!(is_symbol('#'));
+        assert!(is_symbol('+'));
+        assert!(is_symbol('$'));
+        assert!(!is_symbol('.'));
+        assert!(!is_symbol('1'));
+        assert!(!is_symbol('2'));
+        assert!(!is_symbol('3'));
+        assert!(!is_symbol('4'));
+        assert!(!is_symbol('5'));
+        assert!(!is_symbol('6'));
+        assert!(!is_symbol('7'));
+        assert!(!is_symbol('8'));
+        assert!(!is_symbol('9'));
+    }
+
+    #[test]
+    fn reports_ragged_boards() {
+        let error = Day03::parse("467..114..\n...*......\n..35..633")
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.starts_with("3:1: expected a row of 10 cells, found \"..35..633\""));
+    }
+}
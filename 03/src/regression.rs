@@ -0,0 +1,99 @@
+//! Hand-crafted schematics for the edge cases of the adjacency between numbers and symbols
+
+use crate::{parse_input, part_one, part_two};
+
+fn solve(schematic: &str) -> (u64, u64) {
+    let board = parse_input(schematic).unwrap();
+
+    (part_one(&board), part_two(&board))
+}
+
+#[test]
+fn counts_a_star_next_to_several_digits_once() {
+    // The first star touches all three digits, the second one only the last digit. Removing
+    // only consecutive duplicates counted 123 twice next to the first star.
+    assert_eq!(solve(".*.*\n123."), (123, 0));
+}
+
+#[test]
+fn counts_a_number_next_to_several_symbols_once() {
+    assert_eq!(solve("*12#\n.$.."), (12, 0));
+}
+
+#[test]
+fn needs_exactly_two_parts_for_a_gear() {
+    assert_eq!(solve("2.3\n.*.\n4.."), (9, 0));
+    assert_eq!(solve("2.3\n.*.\n..."), (5, 6));
+}
+
+#[test]
+fn keeps_different_numbers_with_the_same_value() {
+    assert_eq!(solve("5*5"), (10, 25));
+    assert_eq!(solve("7..\n.*.\n..7"), (14, 49));
+}
+
+#[test]
+fn splits_numbers_at_the_end_of_a_row() {
+    // 12 and 34 are apart, only 34 touches the star
+    assert_eq!(solve("..12\n34..\n..*."), (34, 0));
+    assert_eq!(solve("...12\n34*.."), (46, 408));
+}
+
+#[test]
+fn finds_numbers_in_the_corners() {
+    assert_eq!(solve("1.2\n.#.\n3.4"), (10, 0));
+    assert_eq!(solve("9.\n.*"), (9, 0));
+    assert_eq!(solve("*.\n.9"), (9, 0));
+}
+
+#[test]
+fn ignores_numbers_without_a_symbol() {
+    assert_eq!(solve("1.2\n...\n3.4"), (0, 0));
+    assert_eq!(solve("12\n34"), (0, 0));
+    assert_eq!(solve(""), (0, 0));
+}
+
+#[test]
+fn follows_the_rules_of_a_variant_legend() {
+    use std::collections::BTreeSet;
+
+    use crate::{sum_gear_ratios, sum_part_numbers, Rules};
+
+    // `~` is blank, only `@` and `#` are symbols and a gear is a `@` next to three parts
+    let board = parse_input("2~3.\n.@..\n4..*\n..5.").unwrap();
+    let rules = Rules {
+        blanks: BTreeSet::from(['~']),
+        symbols: Some(BTreeSet::from(['@', '#'])),
+        gear: '@',
+        gear_parts: 3,
+        diagonal: true,
+    };
+
+    assert_eq!(sum_part_numbers(&board, &rules), 9);
+    assert_eq!(sum_gear_ratios(&board, &rules), 24);
+
+    // Every number only touches the `@` diagonally
+    let rules = Rules {
+        diagonal: false,
+        ..rules
+    };
+    assert_eq!(sum_part_numbers(&board, &rules), 0);
+
+    let board = parse_input("2.\n.@\n..").unwrap();
+    let rules = Rules {
+        gear: '@',
+        gear_parts: 1,
+        ..Rules::default()
+    };
+    assert_eq!(sum_gear_ratios(&board, &rules), 2);
+    assert_eq!(
+        sum_gear_ratios(
+            &board,
+            &Rules {
+                diagonal: false,
+                ..rules
+            }
+        ),
+        0
+    );
+}
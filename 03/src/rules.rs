@@ -0,0 +1,115 @@
+use std::collections::BTreeSet;
+
+use aoc_common::AocError;
+
+/// The legend of a schematic: which characters are symbols and which symbols are gears
+///
+/// Digits 0-9 always make up the numbers. A character that is neither a digit, a blank
+/// nor a symbol only separates numbers, like a blank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    pub blanks: BTreeSet<char>,
+    /// None when every character that is not a digit or a blank is a symbol
+    pub symbols: Option<BTreeSet<char>>,
+    pub gear: char,
+    /// The number of parts next to a gear symbol that makes it a gear
+    pub gear_parts: usize,
+    /// Whether a symbol diagonally next to a digit is adjacent to its number
+    pub diagonal: bool,
+}
+
+impl Default for Rules {
+    /// The rules of the puzzle: `.` is blank, everything else is a symbol and a gear is
+    /// a `*` next to exactly two parts, also diagonally
+    fn default() -> Rules {
+        Rules {
+            blanks: BTreeSet::from(['.']),
+            symbols: None,
+            gear: '*',
+            gear_parts: 2,
+            diagonal: true,
+        }
+    }
+}
+
+impl Rules {
+    pub fn is_symbol(&self, ch: char) -> bool {
+        !ch.is_ascii_digit()
+            && !self.blanks.contains(&ch)
+            && self
+                .symbols
+                .as_ref()
+                .is_none_or(|symbols| symbols.contains(&ch))
+    }
+
+    /// The gear must be a symbol and need at least one part, digits cannot be blanks
+    pub fn validate(&self) -> Result<(), AocError> {
+        if let Some(digit) = self.blanks.iter().find(|ch| ch.is_ascii_digit()) {
+            return Err(AocError::Invalid(format!(
+                "The digit {} cannot be a blank",
+                digit
+            )));
+        }
+
+        if !self.is_symbol(self.gear) {
+            return Err(AocError::Invalid(format!(
+                "The gear {:?} is not a symbol",
+                self.gear
+            )));
+        }
+
+        if self.gear_parts == 0 {
+            return Err(AocError::Invalid(
+                "A gear needs at least one part".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_classify_characters() {
+        let rules = Rules::default();
+        assert!(rules.is_symbol('*'));
+        assert!(rules.is_symbol('~'));
+        assert!(!rules.is_symbol('.'));
+        assert!(!rules.is_symbol('7'));
+
+        let rules = Rules {
+            blanks: BTreeSet::from(['.', '~']),
+            symbols: Some(BTreeSet::from(['#', '@'])),
+            gear: '@',
+            ..Rules::default()
+        };
+        assert!(rules.is_symbol('@'));
+        assert!(!rules.is_symbol('~'));
+        assert!(!rules.is_symbol('*'));
+        assert!(rules.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_inconsistent_rules() {
+        let rules = Rules {
+            symbols: Some(BTreeSet::from(['#'])),
+            ..Rules::default()
+        };
+        assert!(rules.validate().is_err());
+
+        let rules = Rules {
+            blanks: BTreeSet::from(['.', '0']),
+            ..Rules::default()
+        };
+        assert!(rules.validate().is_err());
+
+        let rules = Rules {
+            gear_parts: 0,
+            ..Rules::default()
+        };
+        assert!(rules.validate().is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::RangeBounds;
+
+use aoc_common::{Grid, Point};
+
+use crate::{get_numbers, MachineNumber, Rules};
+
+/// A symbol of the schematic and the part numbers next to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub point: Point,
+    pub ch: char,
+    /// From left to right and top to bottom
+    pub parts: Vec<u64>,
+}
+
+/// Every symbol of a schematic with the numbers next to it, of any schematic size
+#[derive(Debug)]
+pub struct SymbolIndex {
+    numbers: Vec<MachineNumber>,
+    /// Every symbol with the indices in `numbers` of the numbers next to it
+    adjacent: HashMap<Point, (char, BTreeSet<usize>)>,
+}
+
+impl SymbolIndex {
+    /// The index with the rules of the puzzle
+    pub fn new(board: &Grid<char>) -> SymbolIndex {
+        SymbolIndex::with_rules(board, &Rules::default())
+    }
+
+    pub fn with_rules(board: &Grid<char>, rules: &Rules) -> SymbolIndex {
+        let numbers = get_numbers(board);
+        let mut adjacent: HashMap<Point, (char, BTreeSet<usize>)> = board
+            .iter()
+            .filter(|(_, &ch)| rules.is_symbol(ch))
+            .map(|(point, &ch)| (point, (ch, BTreeSet::new())))
+            .collect();
+
+        for (index, number) in numbers.iter().enumerate() {
+            for point in number.get_adjacent_cells(board, rules.diagonal) {
+                if let Some((_, indices)) = adjacent.get_mut(&point) {
+                    indices.insert(index);
+                }
+            }
+        }
+
+        SymbolIndex { numbers, adjacent }
+    }
+
+    /// The symbol at the point, None when there is no symbol
+    pub fn get(&self, point: Point) -> Option<Symbol> {
+        let (ch, indices) = self.adjacent.get(&point)?;
+
+        Some(Symbol {
+            point,
+            ch: *ch,
+            parts: indices
+                .iter()
+                .map(|&index| self.numbers[index].get_value())
+                .collect(),
+        })
+    }
+
+    /// Every symbol in reading order
+    pub fn symbols(&self) -> Vec<Symbol> {
+        let mut points: Vec<Point> = self.adjacent.keys().copied().collect();
+        points.sort_by_key(|&(x, y)| (y, x));
+
+        points
+            .into_iter()
+            .filter_map(|point| self.get(point))
+            .collect()
+    }
+
+    /// The symbols `ch` with a number of parts in the range, like the `*` with exactly
+    /// two parts (`2..=2`) or the `#` with three or more (`3..`)
+    pub fn query<R: RangeBounds<usize>>(&self, ch: char, parts: R) -> Vec<Symbol> {
+        self.symbols()
+            .into_iter()
+            .filter(|symbol| symbol.ch == ch && parts.contains(&symbol.parts.len()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, EXAMPLE};
+
+    #[test]
+    fn can_query_symbols() {
+        let index = SymbolIndex::new(&parse_input(EXAMPLE).unwrap());
+        let gears: Vec<(Point, Vec<u64>)> = index
+            .query('*', 2..=2)
+            .into_iter()
+            .map(|symbol| (symbol.point, symbol.parts))
+            .collect();
+
+        assert_eq!(gears, [((3, 1), vec![467, 35]), ((5, 8), vec![755, 598])]);
+        assert_eq!(index.query('*', 1..2)[0].parts, [617]);
+        assert_eq!(index.query('#', 3..), []);
+        assert_eq!(index.symbols().len(), 6);
+        assert_eq!(index.get((0, 0)), None);
+    }
+
+    #[test]
+    fn can_index_schematics_larger_than_999_cells() {
+        // (1001, 0) and (100, 1000) were both "1001000" when the index was keyed by padded strings
+        let mut board = Grid::filled(1002, 1001, '.');
+        board[(1001, 0)] = '*';
+        board[(1000, 0)] = '2';
+        board[(100, 1000)] = '*';
+        board[(99, 1000)] = '3';
+        board[(101, 1000)] = '4';
+
+        let index = SymbolIndex::new(&board);
+        assert_eq!(index.get((1001, 0)).unwrap().parts, [2]);
+        assert_eq!(index.get((100, 1000)).unwrap().parts, [3, 4]);
+    }
+}
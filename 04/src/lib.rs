@@ -0,0 +1,215 @@
+use aoc_common::parse::{parse_number, parse_numbers, split_once_or_error};
+use aoc_common::{trace, AocError, Detail, Example, ParseError, Part, Solution};
+use std::cmp;
+
+// (card number, winning numbers, game numbers)
+type ScratchCard = (u32, Vec<u32>, Vec<u32>);
+
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<ScratchCard>;
+    type Answer = u32;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("13"),
+        part_two: Some("30"),
+    }];
+
+    fn parse(raw_input: &str) -> Result<Vec<ScratchCard>, AocError> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(scratch_cards: &Vec<ScratchCard>) -> Result<u32, AocError> {
+        Ok(part_one(scratch_cards))
+    }
+
+    fn part_two(scratch_cards: &Vec<ScratchCard>) -> Result<u32, AocError> {
+        Ok(part_two(scratch_cards))
+    }
+
+    /// The number of copies of every card, the original included
+    fn details(scratch_cards: &Vec<ScratchCard>, part: Part) -> Result<Vec<Detail>, AocError> {
+        match part {
+            Part::One => Ok(vec![]),
+            Part::Two => Ok(get_copies_per_card(scratch_cards)
+                .into_iter()
+                .map(|(card_number, copies)| Detail::new(format!("Card {}", card_number), copies))
+                .collect()),
+        }
+    }
+}
+
+fn part_one(scratch_cards: &[ScratchCard]) -> u32 {
+    scratch_cards
+        .iter()
+        .map(|scratch_card| {
+            let number_of_winning_numbers: u32 =
+                u32::try_from(get_winning_numbers(scratch_card).len())
+                    .expect("Cannot convert usize to u32");
+
+            if number_of_winning_numbers < 1 {
+                return 0;
+            } else if number_of_winning_numbers == 1 {
+                return 1;
+            }
+
+            let base: u32 = 2;
+            let num = base.pow(number_of_winning_numbers - 1);
+
+            trace!(
+                "scratch_card: {:?}, number_of_winning_numbers: {}, num: {}",
+                scratch_card,
+                number_of_winning_numbers,
+                num
+            );
+
+            num
+        })
+        .sum()
+}
+
+/// Every original card and every copy it wins, see `get_copies_per_card`
+fn part_two(scratch_cards: &[ScratchCard]) -> u32 {
+    get_copies_per_card(scratch_cards)
+        .into_iter()
+        .map(|(_, copies)| copies)
+        .sum()
+}
+
+// Game number
+// numbers
+// game numbers
+// (gameNr: u32, numbers: Vec<u32>, game_numbers: Vec<u32>)
+
+fn parse_input(result: &str) -> Result<Vec<ScratchCard>, ParseError> {
+    result
+        .lines()
+        .map(|s| parse_line(s.trim()).map_err(|error| error.locate(result)))
+        .collect()
+}
+
+fn parse_line(str: &str) -> Result<(u32, Vec<u32>, Vec<u32>), ParseError> {
+    let (card_str, winning_and_game_numbers_str) = split_once_or_error(str, ':')?;
+
+    let card_number: u32 = {
+        let card_parts = card_str.split_whitespace().collect::<Vec<&str>>();
+
+        match card_parts.get(1) {
+            Some(card_number) => parse_number(card_number)?,
+            None => {
+                return Err(ParseError::new(
+                    &card_str[card_str.len()..],
+                    "a card number",
+                ))
+            }
+        }
+    };
+
+    let (winning_numbers, game_numbers) = {
+        let (winning_numbers_str, game_numbers_str) =
+            split_once_or_error(winning_and_game_numbers_str, '|')?;
+
+        (
+            parse_numbers::<u32>(winning_numbers_str)?,
+            parse_numbers::<u32>(game_numbers_str)?,
+        )
+    };
+
+    trace!(
+        "card_number {}, winning_numbers: {:?}, game_numbers: {:?}",
+        card_number,
+        winning_numbers,
+        game_numbers
+    );
+
+    Ok((card_number, winning_numbers, game_numbers))
+}
+
+/// Every card wins copies of the next cards, once for every copy of itself
+fn get_copies_per_card(scratch_cards: &[ScratchCard]) -> Vec<(u32, u32)> {
+    let mut copies: Vec<u32> = vec![1; scratch_cards.len()];
+
+    for (index, scratch_card) in scratch_cards.iter().enumerate() {
+        let number_of_winning_numbers = get_winning_numbers(scratch_card).len();
+        let last_index = cmp::min(index + number_of_winning_numbers, scratch_cards.len() - 1);
+
+        for next_index in index + 1..=last_index {
+            copies[next_index] += copies[index];
+        }
+    }
+
+    scratch_cards
+        .iter()
+        .map(|scratch_card| scratch_card.0)
+        .zip(copies)
+        .collect()
+}
+
+fn get_winning_numbers((_, winning_numbers, game_numbers): &(u32, Vec<u32>, Vec<u32>)) -> Vec<u32> {
+    winning_numbers
+        .iter()
+        .filter(|x| game_numbers.contains(x))
+        .copied()
+        .collect::<Vec<u32>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_example() {
+        let input = r#"
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+        "#
+        .trim()
+        .to_string();
+
+        let scratch_cards = parse_input(&input).unwrap();
+
+        assert_eq!(part_one(&scratch_cards), 13);
+        assert_eq!(part_two(&scratch_cards), 30);
+    }
+
+    #[test]
+    fn reports_malformed_cards() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 32 20 61 30";
+        let error = parse_input(input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 23));
+        assert_eq!(error.expected, "'|'");
+
+        let input = "Card 1: 41 48 | 83 86\nCard two: 13 32 | 61 30";
+        let error = parse_input(input).unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (2, 6, "two")
+        );
+    }
+
+    #[test]
+    fn can_get_copies_per_card() {
+        let scratch_cards = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(
+            get_copies_per_card(&scratch_cards),
+            vec![(1, 1), (2, 2), (3, 4), (4, 8), (5, 14), (6, 1)]
+        );
+    }
+}
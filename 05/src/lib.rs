@@ -0,0 +1,488 @@
+use aoc_common::parse::{parse_labeled_numbers, parse_numbers};
+use aoc_common::{debug, trace, AocError, Example, ParseError, Solution};
+use std::cmp;
+use std::collections::HashMap;
+
+// (dest, source, range)
+type Mapping = (i64, i64, i64);
+
+// (name, mappings)
+type Block<'a> = (&'a str, Vec<Mapping>);
+
+// (source, range)
+type Slice = (i64, i64);
+
+// The maps from seed to location, in the order they are applied
+const BLOCK_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// (seeds, mappings per block name, the raw input to locate the seeds in)
+type Almanac<'a> = (Vec<i64>, HashMap<String, Vec<Mapping>>, &'a str);
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Almanac<'a>;
+    type Answer = i64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("35"),
+        part_two: Some("46"),
+    }];
+
+    fn parse(raw_input: &str) -> Result<Almanac<'_>, AocError> {
+        let (seeds, blocks) = parse_input(raw_input)?;
+        let hash = convert_blocks_into_hashes(blocks);
+
+        if let Some(name) = BLOCK_NAMES.iter().find(|name| !hash.contains_key(**name)) {
+            let end = &raw_input[raw_input.len()..];
+            let error = ParseError::new(end, format!("a {} map", name));
+
+            return Err(error.locate(raw_input).into());
+        }
+
+        Ok((seeds, hash, raw_input))
+    }
+
+    fn part_one((seeds, hash, _): &Almanac) -> Result<i64, AocError> {
+        Ok(part_one(seeds, hash))
+    }
+
+    fn part_two((seeds, hash, raw_input): &Almanac) -> Result<i64, AocError> {
+        Ok(part_two(seeds, hash, raw_input)?)
+    }
+}
+
+fn parse_input(raw_input: &str) -> Result<(Vec<i64>, Vec<Block<'_>>), ParseError> {
+    let pieces = raw_input
+        .split("\n\n")
+        .map(|x| x.trim())
+        .collect::<Vec<&str>>();
+
+    let seeds: Vec<i64> =
+        parse_labeled_numbers(pieces[0]).map_err(|error| error.locate(raw_input))?;
+
+    let blocks = pieces
+        .iter()
+        .skip(1)
+        .map(|x| parse_block(x))
+        .collect::<Result<Vec<Block>, ParseError>>()
+        .map_err(|error| error.locate(raw_input))?;
+
+    debug!("seeds: {:?}, blocks: {:?}", seeds, blocks);
+
+    Ok((seeds, blocks))
+}
+
+fn parse_block(block_str: &str) -> Result<Block<'_>, ParseError> {
+    let mut lines = block_str.lines();
+
+    let header = lines.next().unwrap_or(block_str);
+    let name = header
+        .trim()
+        .strip_suffix("map:")
+        .map(|x| x.trim())
+        .ok_or_else(|| ParseError::new(header.trim(), "a header like \"seed-to-soil map:\""))?;
+
+    let mappings: Vec<(i64, i64, i64)> = lines
+        .map(parse_block_line)
+        .collect::<Result<Vec<(i64, i64, i64)>, ParseError>>()?;
+
+    trace!("name: {}, mappings: {:?}", name, mappings);
+
+    Ok((name, mappings))
+}
+
+fn parse_block_line(str: &str) -> Result<(i64, i64, i64), ParseError> {
+    match parse_numbers(str)?[..] {
+        [dest, source, range] => Ok((dest, source, range)),
+        _ => Err(ParseError::new(
+            str.trim(),
+            "three numbers: destination, source and range",
+        )),
+    }
+}
+
+fn convert_blocks_into_hashes(blocks: Vec<Block<'_>>) -> HashMap<String, Vec<(i64, i64, i64)>> {
+    blocks
+        .iter()
+        .fold(HashMap::new(), |mut acc, (name, values)| {
+            // acc.insert(name.to_string(), values_to_hashmap(values));
+            acc.insert(name.to_string(), values.clone());
+            acc
+        })
+}
+
+/// The mappings of every block in the order of `BLOCK_NAMES`, `Day05::parse` checks that
+/// they all exist
+fn ordered_blocks(hash: &HashMap<String, Vec<Mapping>>) -> impl Iterator<Item = &[Mapping]> {
+    BLOCK_NAMES
+        .iter()
+        .filter_map(|name| hash.get(*name))
+        .map(Vec::as_slice)
+}
+
+fn get_hash_value(values: &[Mapping], source: i64) -> i64 {
+    for (dest, src, range) in values {
+        trace!(
+            "source: {}, dest: {}, src: {}, range: {}, cmp::max: {}, {} {}",
+            source,
+            dest,
+            src,
+            range,
+            cmp::max(src + range - 1, *src),
+            source >= *src,
+            source <= cmp::max(src + range - 1, *src)
+        );
+
+        if source >= *src && source <= cmp::max(src + range - 1, *src) {
+            let increment = source - src;
+            return dest + increment;
+        }
+    }
+
+    source
+}
+
+/**
+ * Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82
+ * [seed, soil, fertilizer, water, light, temperature, humidity, location]
+ */
+fn get_all_category_values(hash: &HashMap<String, Vec<Mapping>>, seed: i64) -> Vec<i64> {
+    let categories = ordered_blocks(hash).scan(seed, |value, mappings| {
+        *value = get_hash_value(mappings, *value);
+        Some(*value)
+    });
+
+    std::iter::once(seed).chain(categories).collect()
+}
+
+fn map_slice(
+    (dest, source, range): &(i64, i64, i64),
+    (slice_source, slice_range): (i64, i64),
+) -> (Vec<Slice>, Vec<Slice>) {
+    let mut new_mapped_slices: Vec<(i64, i64)> = vec![];
+    let mut new_slices: Vec<(i64, i64)> = vec![];
+
+    let slice_end = slice_source + slice_range; // not inclusive
+    let mapping_end = source + range; // not inclusive
+
+    let slice_source_is_in_mapping = slice_source >= *source && slice_source < mapping_end;
+    let slice_end_is_in_mapping = slice_end <= mapping_end && slice_end > *source;
+
+    let mapping_source_is_in_slice = *source >= slice_source && *source < slice_end;
+    let mapping_end_is_in_slice = mapping_end <= slice_end && mapping_end > slice_source;
+
+    let increment = dest - source;
+
+    // slice:   xxxx
+    // mapping: xxxxxx
+
+    // slice:     xxxx
+    // mapping: xxxxxx
+
+    // slice:    xxxx
+    // mapping: xxxxxx
+
+    // slice:   xxxxxx
+    // mapping:  xxxx
+
+    // slice:    xxxx
+    // mapping: xxxxxx
+    if slice_source_is_in_mapping && slice_end_is_in_mapping {
+        new_mapped_slices.push((slice_source + increment, slice_range));
+
+    // slice:       xxxx => xx | xx
+    // mapping: xxxxxx
+    } else if slice_source_is_in_mapping && !slice_end_is_in_mapping {
+        new_mapped_slices.push((slice_source + increment, mapping_end - slice_source));
+        new_slices.push((mapping_end, slice_range - (mapping_end - slice_source)));
+
+    // slice:   xxxx
+    // mapping:   xxxxxx
+    } else if !slice_source_is_in_mapping && slice_end_is_in_mapping {
+        new_slices.push((slice_source, source - slice_source));
+        new_mapped_slices.push((*source + increment, slice_range - (source - slice_source)));
+
+    // slice:   xxxxxx
+    // mapping:   xx
+    } else if mapping_source_is_in_slice && mapping_end_is_in_slice {
+        new_slices.push((slice_source, source - slice_source));
+        new_mapped_slices.push((*source + increment, *range));
+        new_slices.push((mapping_end, slice_range - range - (source - slice_source)));
+    } else {
+        new_slices.push((slice_source, slice_range));
+    }
+
+    (new_mapped_slices, new_slices)
+}
+
+fn map_slices(mappings: &[(i64, i64, i64)], slices: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    let tuple = mappings.iter().fold((vec![], slices), |acc, mapping| {
+        let mut mapped_slices = acc.0;
+        let slices_todo = acc.1;
+
+        let mut new_slices_todo: Vec<(i64, i64)> = vec![];
+
+        for slice_todo in slices_todo {
+            let (new_mapped_slices, new_slices) = map_slice(mapping, slice_todo);
+
+            for new_mapped_slice in new_mapped_slices {
+                mapped_slices.push(new_mapped_slice);
+            }
+
+            for new_slice in new_slices {
+                new_slices_todo.push(new_slice);
+            }
+        }
+
+        (mapped_slices, new_slices_todo)
+    });
+
+    let mut mapped_slices = tuple.0;
+    let new_slices_todo = tuple.1;
+
+    for new_slice in new_slices_todo {
+        mapped_slices.push(new_slice);
+    }
+
+    mapped_slices
+}
+
+/// The seeds in pairs of a start and a length, the last seed without a length is an error
+/// located in `raw_input`
+fn chunk_seeds(seeds: &[i64], raw_input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    if let (true, Some(seed)) = (seeds.len() % 2 == 1, seeds.last()) {
+        let seeds_line = raw_input
+            .trim()
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim_end();
+        let end = &seeds_line[seeds_line.len()..];
+        let error = ParseError::new(end, format!("the length of the range of seed {}", seed));
+
+        return Err(error.locate(raw_input));
+    }
+
+    Ok(seeds.chunks_exact(2).map(|x| (x[0], x[1])).collect())
+}
+
+fn part_one(seeds: &[i64], hash: &HashMap<String, Vec<Mapping>>) -> i64 {
+    seeds
+        .iter()
+        .filter_map(|seed| get_all_category_values(hash, *seed).last().copied())
+        .fold(i64::MAX, cmp::min)
+}
+
+/// The seeds are ranges, see `chunk_seeds`
+fn part_two(
+    seeds: &[i64],
+    hash: &HashMap<String, Vec<Mapping>>,
+    raw_input: &str,
+) -> Result<i64, ParseError> {
+    let slices = chunk_seeds(seeds, raw_input)?;
+
+    let slices = ordered_blocks(hash).fold(slices, |slices, mappings| map_slices(mappings, slices));
+
+    Ok(slices
+        .iter()
+        .fold(i64::MAX, |acc, (source, _)| cmp::min(acc, *source)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_get_the_correct_category_values() {
+        let input = r#"
+            seeds: 79 14 55 13
+
+            seed-to-soil map:
+            50 98 2
+            52 50 48
+
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+
+            fertilizer-to-water map:
+            49 53 8
+            0 11 42
+            42 0 7
+            57 7 4
+
+            water-to-light map:
+            88 18 7
+            18 25 70
+
+            light-to-temperature map:
+            45 77 23
+            81 45 19
+            68 64 13
+
+            temperature-to-humidity map:
+            0 69 1
+            1 0 69
+
+            humidity-to-location map:
+            60 56 37
+            56 93 4
+        "#
+        .trim()
+        .to_string();
+
+        let (seeds, blocks) = parse_input(&input).unwrap();
+        let hash = convert_blocks_into_hashes(blocks);
+
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+
+        // Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82.
+        // Seed 14, soil 14, fertilizer 53, water 49, light 42, temperature 42, humidity 43, location 43.
+        // Seed 55, soil 57, fertilizer 57, water 53, light 46, temperature 82, humidity 82, location 86.
+        // Seed 13, soil 13, fertilizer 52, water 41, light 34, temperature 34, humidity 35, location 35.
+        assert_eq!(
+            get_all_category_values(&hash, 79),
+            [79, 81, 81, 81, 74, 78, 78, 82]
+        );
+
+        assert_eq!(
+            get_all_category_values(&hash, 14),
+            [14, 14, 53, 49, 42, 42, 43, 43]
+        );
+
+        assert_eq!(
+            get_all_category_values(&hash, 55),
+            [55, 57, 57, 53, 46, 82, 82, 86]
+        );
+
+        assert_eq!(
+            get_all_category_values(&hash, 13),
+            [13, 13, 52, 41, 34, 34, 35, 35]
+        );
+
+        // assert_eq!(parsed_input, test_board);
+    }
+
+    #[test]
+    fn can_map_slice() {
+        // No mapping (to low)
+        let mapping: (i64, i64, i64) = (4, 5, 2);
+        let slice: (i64, i64) = (1, 2);
+        let mapped_slices: Vec<(i64, i64)> = vec![];
+        let unmapped_slices: Vec<(i64, i64)> = vec![(1, 2)];
+
+        assert_eq!(map_slice(&mapping, slice), (mapped_slices, unmapped_slices));
+
+        // No mapping (to low)
+        let mapping: (i64, i64, i64) = (4, 1, 2);
+        let slice: (i64, i64) = (4, 2);
+        let mapped_slices: Vec<(i64, i64)> = vec![];
+        let unmapped_slices: Vec<(i64, i64)> = vec![(4, 2)];
+
+        assert_eq!(map_slice(&mapping, slice), (mapped_slices, unmapped_slices));
+
+        // Slice within mapping
+        let mapping: (i64, i64, i64) = (4, 1, 6); // 1, 2, 3, 4, 5, 6
+        let slice: (i64, i64) = (2, 2); // 2, 3
+        let mapped_slices: Vec<(i64, i64)> = vec![(5, 2)];
+        let unmapped_slices: Vec<(i64, i64)> = vec![];
+
+        assert_eq!(map_slice(&mapping, slice), (mapped_slices, unmapped_slices));
+
+        // Slice overlapping and to the right of the mapping
+        let mapping: (i64, i64, i64) = (5, 1, 6); // 1, 2, 3, 4, 5, 6
+        let slice: (i64, i64) = (4, 5); // 4, 5, 6, 7, 8
+        let mapped_slices: Vec<(i64, i64)> = vec![(8, 3)];
+        let unmapped_slices: Vec<(i64, i64)> = vec![(7, 2)];
+
+        assert_eq!(map_slice(&mapping, slice), (mapped_slices, unmapped_slices));
+
+        // Slice overlapping and to the left of the mapping
+        let mapping: (i64, i64, i64) = (8, 4, 6); // 4, 5, 6, 7, 8, 9
+        let slice: (i64, i64) = (2, 4); // 2, 3, 4, 5
+        let mapped_slices: Vec<(i64, i64)> = vec![(8, 2)];
+        let unmapped_slices: Vec<(i64, i64)> = vec![(2, 2)];
+
+        assert_eq!(map_slice(&mapping, slice), (mapped_slices, unmapped_slices));
+
+        // Slice overlapping mapping
+        let mapping: (i64, i64, i64) = (8, 4, 2); // 4, 5
+        let slice: (i64, i64) = (2, 6); // 2, 3, 4, 5, 6, 7
+        let mapped_slices: Vec<(i64, i64)> = vec![(8, 2)];
+        let unmapped_slices: Vec<(i64, i64)> = vec![(2, 2), (6, 2)];
+
+        assert_eq!(map_slice(&mapping, slice), (mapped_slices, unmapped_slices));
+    }
+
+    #[test]
+    fn reports_malformed_almanacs() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50";
+        let error = parse_input(input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(
+            error.expected,
+            "three numbers: destination, source and range"
+        );
+
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2";
+        let error = Day05::parse(input).unwrap_err().to_string();
+
+        assert!(error.starts_with("4:8: expected a soil-to-fertilizer map, found end of line"));
+
+        // Part two needs a length for every seed, part one does not
+        let input = EXAMPLE.replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+        let almanac = Day05::parse(&input).unwrap();
+        let error = Day05::part_two(&almanac).unwrap_err().to_string();
+
+        assert_eq!(Day05::part_one(&almanac).unwrap(), 43);
+        assert!(error
+            .starts_with("1:16: expected the length of the range of seed 55, found end of line"));
+    }
+}
@@ -0,0 +1,158 @@
+use aoc_common::parse::{parse_labeled_numbers, parse_number, parse_numbers, split_once_or_error};
+use aoc_common::{debug, AocError, Example, ParseError, Solution};
+
+// (time, record distance)
+type Race = (i64, i64);
+
+const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // Every column is a race for part one, part two reads the input again without the spaces
+    // between the numbers, so a kerned number that overflows only fails part two
+    type Input<'a> = (Vec<Race>, &'a str);
+    type Answer = i64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("288"),
+        part_two: Some("71503"),
+    }];
+
+    fn parse(raw_input: &str) -> Result<(Vec<Race>, &str), AocError> {
+        let races = parse_input_one(raw_input).map_err(|error| error.locate(raw_input))?;
+
+        Ok((races, raw_input))
+    }
+
+    fn part_one((races, _): &(Vec<Race>, &str)) -> Result<i64, AocError> {
+        Ok(part_one(races))
+    }
+
+    fn part_two((_, raw_input): &(Vec<Race>, &str)) -> Result<i64, AocError> {
+        let race = parse_input_two(raw_input).map_err(|error| error.locate(raw_input))?;
+
+        Ok(part_two(race))
+    }
+}
+
+fn parse_input_one(raw_input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut lines = raw_input.lines();
+
+    let times: Vec<i64> = parse_labeled_numbers(next_line(&mut lines, raw_input, "a time line")?)?;
+    let distance_line = next_line(&mut lines, raw_input, "a distance line")?;
+    let distances: Vec<i64> = parse_labeled_numbers(distance_line)?;
+
+    let mut races: Vec<(i64, i64)> = vec![];
+
+    for (index, time) in times.into_iter().enumerate() {
+        let distance = *distances.get(index).ok_or_else(|| {
+            ParseError::new(
+                &distance_line[distance_line.len()..],
+                "a distance for every time",
+            )
+        })?;
+
+        races.push((time, distance));
+    }
+
+    Ok(races)
+}
+
+fn parse_input_two(raw_input: &str) -> Result<(i64, i64), ParseError> {
+    let mut lines = raw_input.lines();
+
+    let time = parse_kerned_number(next_line(&mut lines, raw_input, "a time line")?)?;
+    let distance = parse_kerned_number(next_line(&mut lines, raw_input, "a distance line")?)?;
+
+    Ok((time, distance))
+}
+
+/// A missing line is reported at the end of the input
+fn next_line<'a>(
+    lines: &mut std::str::Lines<'a>,
+    raw_input: &'a str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    lines
+        .next()
+        .ok_or_else(|| ParseError::new(&raw_input[raw_input.len()..], expected))
+}
+
+/// Ignore the spaces between the numbers, "Time:  7  15   30" => 71530
+fn parse_kerned_number(line: &str) -> Result<i64, ParseError> {
+    let (_, numbers) = split_once_or_error(line, ':')?;
+
+    // Validate every part first, so the error points at the offending one
+    parse_numbers::<i64>(numbers)?;
+
+    parse_number(&numbers.split_whitespace().collect::<Vec<&str>>().join(""))
+        .map_err(|_| ParseError::new(numbers.trim(), "a number that fits in 64 bits"))
+}
+
+fn get_distance(time: i64, index: i64) -> i64 {
+    let running_time = time - index;
+    running_time * index
+}
+
+fn get_number_of_winning_races((time, record_distance): (i64, i64)) -> i64 {
+    let winning_distances: Vec<i64> = (1..time)
+        .map(|num| get_distance(time, num))
+        .filter(|x| *x > record_distance)
+        .collect::<Vec<i64>>();
+
+    i64::try_from(winning_distances.len()).expect("Cannot transform usize into i64")
+}
+
+fn part_one(races: &[Race]) -> i64 {
+    debug!("races: {:?}", races);
+
+    races
+        .iter()
+        .map(|race| get_number_of_winning_races(*race))
+        .product()
+}
+
+fn part_two(race: Race) -> i64 {
+    debug!("race: {:?}", race);
+
+    get_number_of_winning_races(race)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Part;
+
+    #[test]
+    fn can_solve_example() {
+        assert_eq!(Day06::solve(EXAMPLE, Part::One).unwrap(), 288);
+        assert_eq!(Day06::solve(EXAMPLE, Part::Two).unwrap(), 71503);
+    }
+
+    #[test]
+    fn reports_malformed_races() {
+        let error = Day06::parse("Time:      7  15   30")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("1:22: expected a distance line, found end of line"));
+
+        let error = Day06::parse("Time:      7  1x   30\nDistance:  9  40  200")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("1:15: expected a number, found \"1x\""));
+    }
+
+    #[test]
+    fn reports_kerned_numbers_that_overflow_in_part_two_only() {
+        let input = format!("Time: {}\nDistance: {}", "1 ".repeat(20), "0 ".repeat(20));
+        let races = Day06::parse(&input).unwrap();
+        let error = Day06::part_two(&races).unwrap_err().to_string();
+
+        assert_eq!(Day06::part_one(&races).unwrap(), 0);
+        assert!(error.starts_with("1:7: expected a number that fits in 64 bits"));
+    }
+}
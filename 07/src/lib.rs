@@ -0,0 +1,472 @@
+use aoc_common::parse::parse_number;
+use aoc_common::{debug, trace, AocError, Detail, Example, ParseError, Part, Solution};
+use std::cmp::Ordering::Equal;
+use std::collections::HashMap;
+
+// (cards, bid)
+type Hand = ([i64; 5], i64);
+
+const CARD_EXPECTED: &str = "a card like A, K, Q, J, T or 2-9";
+
+const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    // The jokers are worth 11 in part one and 1 in part two
+    type Input<'a> = (Vec<Hand>, Vec<Hand>);
+    type Answer = i64;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("6440"),
+        part_two: Some("5905"),
+    }];
+
+    fn parse(raw_input: &str) -> Result<(Vec<Hand>, Vec<Hand>), AocError> {
+        let sets_one = parse_input_one(raw_input).map_err(|error| error.locate(raw_input))?;
+        let sets_two = parse_input_two(raw_input).map_err(|error| error.locate(raw_input))?;
+
+        Ok((sets_one, sets_two))
+    }
+
+    fn part_one((sets, _): &(Vec<Hand>, Vec<Hand>)) -> Result<i64, AocError> {
+        Ok(part_one(sets.clone()))
+    }
+
+    fn part_two((_, sets): &(Vec<Hand>, Vec<Hand>)) -> Result<i64, AocError> {
+        Ok(part_two(sets.clone()))
+    }
+
+    /// The rank of every hand, from the weakest to the strongest
+    fn details(
+        (sets_one, sets_two): &(Vec<Hand>, Vec<Hand>),
+        part: Part,
+    ) -> Result<Vec<Detail>, AocError> {
+        let mut sets = match part {
+            Part::One => sets_one.clone(),
+            Part::Two => sets_two.clone(),
+        };
+
+        let sets = match part {
+            Part::One => sort_sets_one(&mut sets),
+            Part::Two => sort_sets_two(&mut sets),
+        };
+
+        Ok(sets
+            .iter()
+            .enumerate()
+            .map(|(index, (cards, _))| Detail::new(cards_to_string(cards), index + 1))
+            .collect())
+    }
+}
+
+fn parse_input_one(raw_input: &str) -> Result<Vec<Hand>, ParseError> {
+    raw_input.lines().map(parse_line_one).collect()
+}
+
+fn parse_line_one(line: &str) -> Result<Hand, ParseError> {
+    let (hand, bid) = split_hand_and_bid(line)?;
+
+    let cards = hand
+        .split("")
+        .map(|x| x.trim())
+        .filter(|x| x != &"")
+        .map(map_card_to_number_one)
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+
+    let cards_array = vector_to_array::<i64, 5>(cards).map_err(|_| five_cards_error(hand))?;
+
+    Ok((cards_array, bid))
+}
+
+fn map_card_to_number_one(ch: &str) -> Result<i64, ParseError> {
+    trace!("char: {}", ch);
+
+    match ch {
+        "A" => Ok(14),
+        "K" => Ok(13),
+        "Q" => Ok(12),
+        "J" => Ok(11),
+        "T" => Ok(10),
+        "9" | "8" | "7" | "6" | "5" | "4" | "3" | "2" => parse_number(ch),
+        _ => Err(ParseError::new(ch, CARD_EXPECTED)),
+    }
+}
+
+fn parse_input_two(raw_input: &str) -> Result<Vec<Hand>, ParseError> {
+    raw_input.lines().map(parse_line_two).collect()
+}
+
+fn parse_line_two(line: &str) -> Result<Hand, ParseError> {
+    let (hand, bid) = split_hand_and_bid(line)?;
+
+    let cards = hand
+        .split("")
+        .map(|x| x.trim())
+        .filter(|x| x != &"")
+        .map(map_card_to_number_two)
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+
+    let cards_array = vector_to_array::<i64, 5>(cards).map_err(|_| five_cards_error(hand))?;
+
+    Ok((cards_array, bid))
+}
+
+fn map_card_to_number_two(ch: &str) -> Result<i64, ParseError> {
+    match ch {
+        "A" => Ok(14),
+        "K" => Ok(13),
+        "Q" => Ok(12),
+        "T" => Ok(10),
+        "9" | "8" | "7" | "6" | "5" | "4" | "3" | "2" => parse_number(ch),
+        "J" => Ok(1),
+        _ => Err(ParseError::new(ch, CARD_EXPECTED)),
+    }
+}
+
+/// "32T3K 765" => ("32T3K", 765)
+fn split_hand_and_bid(line: &str) -> Result<(&str, i64), ParseError> {
+    let mut parts_iter = line.split_whitespace();
+
+    let hand = parts_iter
+        .next()
+        .ok_or_else(|| ParseError::new(line, "a hand of cards"))?;
+    let bid = parts_iter
+        .next()
+        .ok_or_else(|| ParseError::new(&line[line.len()..], "a bid"))?;
+
+    Ok((hand, parse_number(bid)?))
+}
+
+fn five_cards_error(hand: &str) -> ParseError {
+    ParseError::new(hand, "a hand of 5 cards")
+}
+
+/// [3, 2, 10, 3, 13] => "32T3K", the jokers of part two are J as well
+fn cards_to_string(cards: &[i64; 5]) -> String {
+    cards
+        .iter()
+        .map(|card| match card {
+            14 => 'A',
+            13 => 'K',
+            12 => 'Q',
+            11 | 1 => 'J',
+            10 => 'T',
+            _ => char::from_digit(*card as u32, 10).unwrap_or('?'),
+        })
+        .collect()
+}
+
+fn vector_to_array<T, const N: usize>(v: Vec<T>) -> Result<[T; N], Vec<T>> {
+    v.try_into()
+}
+
+fn map_cards_to_type_one(cards: &[i64; 5]) -> i64 {
+    // 7: Five of a kind, where all five cards have the same label: AAAAA
+    // 6: Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+    // 5: Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+    // 4: Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+    // 3: Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+    // 2: One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+    // 1: High card, where all cards' labels are distinct: 23456
+
+    let grouped: HashMap<i64, i64> = cards.iter().fold(HashMap::new(), |mut acc, c| {
+        let def: i64 = 0;
+        let i: i64 = *acc.get(c).unwrap_or(&def);
+        acc.insert(*c, i + 1);
+        acc
+    });
+
+    trace!("grouped: {:?}", grouped);
+
+    let def: (i64, i64) = (0, 0);
+    let longest = grouped.iter().fold(def, |acc, card| {
+        if *card.1 > acc.1 {
+            (*card.0, *card.1)
+        } else {
+            acc
+        }
+    });
+
+    trace!("longest: {:?}", longest);
+
+    let second_longest = grouped
+        .into_iter()
+        .filter(|(card, _)| card != &longest.0)
+        .fold(def, |acc, card| {
+            if card.1 > acc.1 {
+                (card.0, card.1)
+            } else {
+                acc
+            }
+        });
+
+    trace!("second_longest: {:?}", second_longest);
+
+    if longest.1 == 5 {
+        return 7;
+    } else if longest.1 == 4 {
+        return 6;
+    } else if longest.1 == 3 && second_longest.1 == 2 {
+        return 5;
+    } else if longest.1 == 3 {
+        return 4;
+    } else if longest.1 == 2 && second_longest.1 == 2 {
+        return 3;
+    } else if longest.1 == 2 {
+        return 2;
+    }
+
+    1
+}
+
+fn sort_sets_one(sets: &mut Vec<([i64; 5], i64)>) -> &mut Vec<([i64; 5], i64)> {
+    let hand_type_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| {
+        map_cards_to_type_one(&l.0).cmp(&map_cards_to_type_one(&r.0))
+    };
+
+    let first_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[0].cmp(&r.0[0]);
+    let second_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[1].cmp(&r.0[1]);
+    let third_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[2].cmp(&r.0[2]);
+    let fourth_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[3].cmp(&r.0[3]);
+    let fifth_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[4].cmp(&r.0[4]);
+
+    sets.sort_by(|a, b| {
+        let result = hand_type_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = first_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = second_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = third_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = fourth_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        fifth_cmp(a, b)
+    });
+
+    sets
+}
+
+fn map_cards_to_type_two(cards: &[i64; 5]) -> i64 {
+    // 7: Five of a kind, where all five cards have the same label: AAAAA
+    // 6: Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+    // 5: Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+    // 4: Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+    // 3: Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+    // 2: One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+    // 1: High card, where all cards' labels are distinct: 23456
+
+    let grouped: HashMap<i64, i64> = cards.iter().fold(HashMap::new(), |mut acc, c| {
+        let def: i64 = 0;
+        let i: i64 = *acc.get(c).unwrap_or(&def);
+        acc.insert(*c, i + 1);
+        acc
+    });
+
+    trace!("grouped: {:?}", grouped);
+
+    let def: (i64, i64) = (0, 0);
+    let longest = grouped
+        .iter()
+        .filter(|(card, _)| card != &&i64::from(1))
+        .fold(def, |acc, card| {
+            if *card.1 > acc.1 {
+                (*card.0, *card.1)
+            } else {
+                acc
+            }
+        });
+
+    trace!("longest: {:?}", longest);
+
+    let second_longest = grouped
+        .iter()
+        .filter(|(card, _)| card != &&i64::from(1))
+        .filter(|(card, _)| card != &&longest.0)
+        .fold(def, |acc, card| {
+            if card.1 > &acc.1 {
+                (*card.0, *card.1)
+            } else {
+                acc
+            }
+        });
+
+    trace!("second_longest: {:?}", second_longest);
+
+    let longest_combo = longest.1 + grouped.get(&i64::from(1)).unwrap_or(&i64::from(0));
+
+    if longest_combo == 5 {
+        return 7;
+    } else if longest_combo == 4 {
+        return 6;
+    } else if longest_combo == 3 && second_longest.1 == 2 {
+        return 5;
+    } else if longest_combo == 3 {
+        return 4;
+    } else if longest_combo == 2 && second_longest.1 == 2 {
+        return 3;
+    } else if longest_combo == 2 {
+        return 2;
+    }
+
+    1
+}
+
+fn sort_sets_two(sets: &mut Vec<([i64; 5], i64)>) -> &mut Vec<([i64; 5], i64)> {
+    let hand_type_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| {
+        map_cards_to_type_two(&l.0).cmp(&map_cards_to_type_two(&r.0))
+    };
+
+    let first_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[0].cmp(&r.0[0]);
+    let second_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[1].cmp(&r.0[1]);
+    let third_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[2].cmp(&r.0[2]);
+    let fourth_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[3].cmp(&r.0[3]);
+    let fifth_cmp = |l: &([i64; 5], i64), r: &([i64; 5], i64)| l.0[4].cmp(&r.0[4]);
+
+    sets.sort_by(|a, b| {
+        let result = hand_type_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = first_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = second_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = third_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        let result = fourth_cmp(a, b);
+        if result != Equal {
+            return result;
+        }
+
+        fifth_cmp(a, b)
+    });
+
+    sets
+}
+
+fn part_one(mut sets: Vec<([i64; 5], i64)>) -> i64 {
+    trace!("sets: {:?}", sets);
+
+    let sets = sort_sets_one(&mut sets);
+    debug!("sorted sets: {:?}", sets);
+
+    sets.iter().enumerate().fold(0, |acc, (index, (_, bid))| {
+        acc + ((i64::try_from(index).expect("Cannot convert usize to i64") + 1) * bid)
+    })
+}
+
+fn part_two(mut sets: Vec<([i64; 5], i64)>) -> i64 {
+    trace!("sets: {:?}", sets);
+
+    let sets = sort_sets_two(&mut sets);
+    debug!("sorted sets: {:?}", sets);
+
+    sets.iter().enumerate().fold(0, |acc, (index, (_, bid))| {
+        acc + ((i64::try_from(index).expect("Cannot convert usize to i64") + 1) * bid)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_part_one() {
+        let input = r#"
+            32T3K 765
+            T55J5 684
+            KK677 28
+            KTJJT 220
+            QQQJA 483
+        "#
+        .trim()
+        .to_string();
+
+        let result = part_one(parse_input_one(&input).unwrap());
+        assert_eq!(result, 6440);
+    }
+
+    #[test]
+    fn can_map_cards_to_type() {
+        assert_eq!(map_cards_to_type_one(&[5, 2, 10, 3, 13]), 1);
+        assert_eq!(map_cards_to_type_one(&[3, 2, 10, 3, 13]), 2);
+        assert_eq!(map_cards_to_type_one(&[13, 13, 6, 7, 7]), 3);
+        assert_eq!(map_cards_to_type_one(&[10, 5, 5, 11, 5]), 4);
+        assert_eq!(map_cards_to_type_one(&[3, 10, 10, 3, 3]), 5);
+        assert_eq!(map_cards_to_type_one(&[3, 3, 10, 3, 3]), 6);
+        assert_eq!(map_cards_to_type_one(&[3, 3, 3, 3, 3]), 7);
+    }
+
+    #[test]
+    fn can_solve_part_two() {
+        let input = r#"
+            32T3K 765
+            T55J5 684
+            KK677 28
+            KTJJT 220
+            QQQJA 483
+        "#
+        .trim()
+        .to_string();
+
+        let result = part_two(parse_input_two(&input).unwrap());
+        assert_eq!(result, 5905);
+    }
+
+    #[test]
+    fn reports_malformed_hands() {
+        let error = Day07::parse("32T3K 765\nT55X5 684")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("2:4: expected a card like A, K, Q, J, T or 2-9, found \"X\""));
+
+        let error = Day07::parse("32T3K 765\nT55J5").unwrap_err().to_string();
+        assert!(error.starts_with("2:6: expected a bid, found end of line"));
+
+        let error = Day07::parse("32T3 765").unwrap_err().to_string();
+        assert!(error.starts_with("1:1: expected a hand of 5 cards, found \"32T3\""));
+    }
+
+    #[test]
+    fn can_rank_hands() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        let details = Day07::details(&input, Part::Two).unwrap();
+        let hands: Vec<&str> = details.iter().map(|x| x.item.as_str()).collect();
+
+        assert_eq!(hands, vec!["32T3K", "KK677", "T55J5", "QQQJA", "KTJJT"]);
+        assert_eq!(details[4], Detail::new("KTJJT", 5));
+    }
+}
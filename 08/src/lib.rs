@@ -0,0 +1,740 @@
+use aoc_common::{debug, trace, AocError, Example, ParseError, Solution};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// (directions, node => (left, right))
+type Network<'a> = (Vec<&'a str>, HashMap<&'a str, (&'a str, &'a str)>);
+
+const EXAMPLE_RL: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+const EXAMPLE_LLR: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+const EXAMPLE_GHOSTS: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Network<'a>;
+    type Answer = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE_RL,
+            part_one: Some("2"),
+            part_two: None,
+        },
+        Example {
+            input: EXAMPLE_LLR,
+            part_one: Some("6"),
+            part_two: None,
+        },
+        Example {
+            input: EXAMPLE_GHOSTS,
+            part_one: None,
+            part_two: Some("6"),
+        },
+    ];
+
+    fn parse(raw_input: &str) -> Result<Network<'_>, AocError> {
+        Ok(parse_input(raw_input).map_err(|error| error.locate(raw_input))?)
+    }
+
+    fn part_one((directions, node_map): &Network<'_>) -> Result<i64, AocError> {
+        part_one(directions, node_map)
+    }
+
+    fn part_two((directions, node_map): &Network<'_>) -> Result<i64, AocError> {
+        part_two(directions, node_map)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RouteNode {
+    node: String,
+    index: usize,
+    steps: i64,
+}
+
+impl PartialEq for RouteNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.index == other.index
+    }
+}
+impl Eq for RouteNode {}
+
+impl Hash for RouteNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+        self.index.hash(state);
+    }
+}
+
+fn parse_input(raw_input: &str) -> Result<Network<'_>, ParseError> {
+    let first_line = raw_input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new(raw_input, "a line of directions"))?;
+
+    let directions = first_line
+        .split("")
+        .map(|x| x.trim())
+        .filter(|x| x != &"")
+        .map(|x| match x {
+            "L" | "R" => Ok(x),
+            _ => Err(ParseError::new(x, "a direction L or R")),
+        })
+        .collect::<Result<Vec<&str>, ParseError>>()?;
+
+    if directions.is_empty() {
+        return Err(ParseError::new(first_line, "a line of directions"));
+    }
+
+    let nodes = raw_input
+        .lines()
+        .skip(2)
+        .map(parse_line)
+        .collect::<Result<Vec<(&str, &str, &str)>, ParseError>>()?;
+    let node_map: HashMap<&str, (&str, &str)> = nodes
+        .iter()
+        .map(|&(key, left, right)| (key, (left, right)))
+        .collect();
+
+    // Every step must lead to a node of the network
+    for (_, left, right) in nodes {
+        for target in [left, right] {
+            if !node_map.contains_key(target) {
+                return Err(ParseError::new(target, "a node defined in the network"));
+            }
+        }
+    }
+
+    Ok((directions, node_map))
+}
+
+fn parse_line(line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let parts = line
+        .split(['=', '(', ')', ','])
+        .map(|x| x.trim())
+        .filter(|x| x != &"")
+        .collect::<Vec<&str>>();
+
+    match parts[..] {
+        [key, left, right] => Ok((key, left, right)),
+        _ => Err(ParseError::new(line, "a node like AAA = (BBB, CCC)")),
+    }
+}
+
+// Create a hashmap with the 3-character node and the index as key and the number of steps as value
+// fn hash_per_node_index_and_steps<'a>(
+//     directions: &[&str],
+//     node_map: &HashMap<&str, (&str, &str)>,
+// ) -> HashMap<RouteNode, RouteNode> {
+//     let mut hash = HashMap::new();
+
+//     for index in 0..directions.len() {
+//         for (node, (_left, _right)) in node_map.into_iter() {
+//             let source_route_node = RouteNode {
+//                 node: String::from(*node),
+//                 index: index,
+//                 steps: 0,
+//             };
+//             let dest_route_node = get_next_z_node(&directions, &node_map, source_route_node);
+
+//             hash.insert(source_route_node, dest_route_node);
+//         }
+//     }
+
+//     hash
+// }
+
+fn fill_hash_till_next_z_node(
+    directions: &[&str],
+    node_map: &HashMap<&str, (&str, &str)>,
+    hash: &mut HashMap<RouteNode, RouteNode>,
+    start_route_node: &RouteNode,
+) -> Result<RouteNode, AocError> {
+    let mut steps = 0i64;
+    let mut index = start_route_node.index;
+
+    let mut node = start_route_node.node.clone();
+
+    let max_index_direction = directions.len() - 1;
+
+    let mut last_route_node = start_route_node.clone();
+    last_route_node.steps = 0;
+
+    let mut route_nodes = vec![last_route_node];
+
+    loop {
+        steps += 1;
+
+        let (left, right) = get_node(node_map, &node)?;
+
+        // The parser only accepts L and R
+        node = match directions[index] {
+            "L" => left.to_string(),
+            _ => right.to_string(),
+        };
+
+        index = if index >= max_index_direction {
+            0
+        } else {
+            index + 1
+        };
+
+        last_route_node = RouteNode {
+            node: node.clone(),
+            index,
+            steps,
+        };
+
+        trace!(
+            "New last_route_node: node: {}, index: {}, steps: {}",
+            node,
+            index,
+            steps
+        );
+
+        let is_done = node.ends_with('Z');
+
+        if is_done {
+            break;
+        } else {
+            if let Some(hashed_route_node) = hash.get(&last_route_node) {
+                trace!(
+                    "Found hashed_route_node - node: {}, index: {}, steps: {}, total_steps: {}, calculated_steps: {}",
+                    hashed_route_node.node,
+                    hashed_route_node.index,
+                    hashed_route_node.steps,
+                    steps,
+                    steps + hashed_route_node.steps
+                );
+                steps += hashed_route_node.steps;
+                last_route_node = RouteNode {
+                    node: hashed_route_node.node.clone(),
+                    index: hashed_route_node.index,
+                    steps,
+                };
+                break;
+            }
+
+            route_nodes.push(last_route_node);
+        }
+    }
+
+    for route_node in route_nodes {
+        let mut new_route_node = last_route_node.clone();
+        trace!(
+            "Calculating steps- node: {}, route_node_steps: {}, steps: {}, calculated_steps: {}",
+            route_node.node,
+            route_node.steps,
+            steps,
+            steps - route_node.steps
+        );
+        new_route_node.steps = steps - route_node.steps;
+        hash.insert(route_node, new_route_node);
+    }
+
+    Ok(last_route_node)
+}
+
+/// The left and right node of a node, the parser checks that every node is defined
+fn get_node<'a>(
+    node_map: &HashMap<&'a str, (&'a str, &'a str)>,
+    node: &str,
+) -> Result<(&'a str, &'a str), AocError> {
+    node_map
+        .get(node)
+        .copied()
+        .ok_or_else(|| AocError::Invalid(format!("The network has no node {}", node)))
+}
+
+fn part_one(directions: &[&str], node_map: &HashMap<&str, (&str, &str)>) -> Result<i64, AocError> {
+    trace!("directions: {:?}", directions);
+    trace!("node_map: {:?}", node_map);
+
+    for node in ["AAA", "ZZZ"] {
+        get_node(node_map, node)?;
+    }
+
+    let mut steps = 0i64;
+    let mut key = "AAA";
+    let mut next_directions = directions.to_vec();
+
+    loop {
+        trace!(
+            "steps: {}, Key: {}, next_directions: {:?}",
+            steps,
+            key,
+            next_directions
+        );
+
+        if key == "ZZZ" {
+            break;
+        }
+
+        if next_directions.is_empty() {
+            next_directions = directions.to_vec();
+        }
+
+        let next_direction = next_directions.remove(0);
+
+        steps += 1;
+
+        let sides = get_node(node_map, key)?;
+
+        // The parser only accepts L and R
+        key = match next_direction {
+            "L" => sides.0,
+            _ => sides.1,
+        }
+    }
+
+    Ok(steps)
+}
+
+fn part_two(directions: &[&str], node_map: &HashMap<&str, (&str, &str)>) -> Result<i64, AocError> {
+    // let hash = hash_per_node_index_and_steps(&directions, &node_map);
+    // trace!("hash: {:?}", hash);
+
+    let start_nodes = node_map
+        .clone()
+        .into_keys()
+        .filter(|x| x.ends_with('A'))
+        .collect::<Vec<&str>>();
+
+    debug!("start_nodes: {:?}", start_nodes);
+
+    let no_start_node = || AocError::Invalid("The network has no node ending in A".to_string());
+
+    if start_nodes.is_empty() {
+        return Err(no_start_node());
+    }
+
+    trace!("directions: {:?}", directions);
+    trace!("node_map: {:?}", node_map);
+
+    let mut is_done = false;
+    let mut route_nodes = start_nodes
+        .iter()
+        .map(|node| RouteNode {
+            node: node.to_string(),
+            index: 0,
+            steps: 0,
+        })
+        .collect::<Vec<RouteNode>>();
+
+    let mut hash: HashMap<RouteNode, RouteNode> = HashMap::new();
+
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 4,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 6,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 3,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 0,
+    //         steps: 6,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22A".to_string(),
+    //         index: 0,
+    //         steps: 0,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 3,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 2,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 4,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11A".to_string(),
+    //         index: 0,
+    //         steps: 0,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 2,
+    //     },
+    // );
+
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22C".to_string(),
+    //         index: 0,
+    //         steps: 2,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 3,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 8,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 10,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11A".to_string(),
+    //         index: 0,
+    //         steps: 0,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 2,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 4,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 6,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 2,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 4,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 6,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 8,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11B".to_string(),
+    //         index: 1,
+    //         steps: 1,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 2,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 3,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 0,
+    //         steps: 6,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22A".to_string(),
+    //         index: 0,
+    //         steps: 0,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 3,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 0,
+    //         steps: 9,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 12,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "22B".to_string(),
+    //         index: 1,
+    //         steps: 1,
+    //     },
+    //     RouteNode {
+    //         node: "22Z".to_string(),
+    //         index: 1,
+    //         steps: 3,
+    //     },
+    // );
+    // hash.insert(
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 10,
+    //     },
+    //     RouteNode {
+    //         node: "11Z".to_string(),
+    //         index: 0,
+    //         steps: 12,
+    //     },
+    // );
+
+    let mut iteration = 0i64;
+
+    while !is_done {
+        iteration += 1;
+
+        let (lowest_index, lowest_steps) = route_nodes
+            .iter()
+            .enumerate()
+            .reduce(|acc, x| if acc.1.steps > x.1.steps { x } else { acc })
+            .ok_or_else(no_start_node)?;
+
+        if (iteration % 1000000) == 0 {
+            debug!(
+                "lowest_index: {}, lowest_steps: {:?}, hash keys: {}",
+                lowest_index,
+                lowest_steps,
+                hash.keys().len()
+            );
+        }
+
+        if let Some(new_route_node) = hash.get(lowest_steps) {
+            trace!("Got RouteNode in hash: {:?}", lowest_steps);
+
+            let mut route_node = new_route_node.clone();
+            route_node.steps += lowest_steps.steps;
+
+            route_nodes[lowest_index] = route_node;
+        } else {
+            trace!("Cannot get RouteNode in hash: {:?}", lowest_steps);
+            // panic!("Should not try to calc route");
+            let new_route_node =
+                fill_hash_till_next_z_node(directions, node_map, &mut hash, lowest_steps)?;
+            let mut route_node = new_route_node.clone();
+            route_node.steps += lowest_steps.steps;
+
+            route_nodes[lowest_index] = route_node;
+        }
+
+        let are_all_on_the_same_step = route_nodes
+            .iter()
+            .map(|x| x.steps)
+            .all(|x| x == route_nodes[0].steps);
+
+        let are_all_z = route_nodes
+            .iter()
+            .all(|route_node| route_node.node.ends_with('Z'));
+
+        is_done = are_all_on_the_same_step && are_all_z;
+    }
+
+    trace!("hash: {:?}", hash);
+
+    Ok(route_nodes[0].steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Part;
+
+    #[test]
+    fn can_solve_part_one() {
+        let input = r#"
+            RL
+
+            AAA = (BBB, CCC)
+            BBB = (DDD, EEE)
+            CCC = (ZZZ, GGG)
+            DDD = (DDD, DDD)
+            EEE = (EEE, EEE)
+            GGG = (GGG, GGG)
+            ZZZ = (ZZZ, ZZZ)
+        "#
+        .trim()
+        .to_string();
+
+        assert_eq!(Day08::solve(&input, Part::One).unwrap(), 2);
+
+        let input = r#"
+            LLR
+
+            AAA = (BBB, BBB)
+            BBB = (AAA, ZZZ)
+            ZZZ = (ZZZ, ZZZ)
+        "#
+        .trim()
+        .to_string();
+
+        assert_eq!(Day08::solve(&input, Part::One).unwrap(), 6);
+    }
+
+    #[test]
+    fn can_solve_part_two() {
+        let input = r#"
+            LR
+
+            11A = (11B, XXX)
+            11B = (XXX, 11Z)
+            11Z = (11B, XXX)
+            22A = (22B, XXX)
+            22B = (22C, 22C)
+            22C = (22Z, 22Z)
+            22Z = (22B, 22B)
+            XXX = (XXX, XXX)
+        "#
+        .trim()
+        .to_string();
+
+        assert_eq!(Day08::solve(&input, Part::Two).unwrap(), 6);
+    }
+
+    #[test]
+    fn can_use_route_node_as_hash_key() {
+        let mut hash: HashMap<RouteNode, i64> = HashMap::new();
+
+        hash.insert(
+            RouteNode {
+                node: "AAA".to_string(),
+                index: 22,
+                steps: 45,
+            },
+            22,
+        );
+
+        let route_node = RouteNode {
+            node: "AAA".to_string(),
+            index: 22,
+            steps: 22,
+        };
+        let value = hash
+            .get(&route_node)
+            .expect("[can_use_route_node_as_hash_key]: Error cannot get routenode");
+
+        assert_eq!(value, &22i64);
+    }
+
+    #[test]
+    fn reports_malformed_networks() {
+        let error = Day08::parse("LXR\n\nAAA = (BBB, CCC)")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("1:2: expected a direction L or R, found \"X\""));
+
+        let error = Day08::parse("LR\n\nAAA = (BBB, CCC)\nBBB = (DDD)")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with("4:1: expected a node like AAA = (BBB, CCC), found \"BBB = (DDD)\"")
+        );
+
+        let error = Day08::parse("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("3:8: expected a node defined in the network, found \"BBB\""));
+
+        let error = Day08::parse("\n\nAAA = (AAA, AAA)")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("1:1: expected a line of directions"));
+    }
+
+    #[test]
+    fn reports_networks_without_start_nodes() {
+        let network = Day08::parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert!(matches!(
+            Day08::part_one(&network),
+            Err(AocError::Invalid(_))
+        ));
+
+        let network = Day08::parse("LR\n\nAAA = (AAA, AAA)").unwrap();
+        assert!(matches!(
+            Day08::part_one(&network),
+            Err(AocError::Invalid(_))
+        ));
+
+        let network = Day08::parse("LR\n\n11B = (11Z, 11Z)\n11Z = (11B, 11B)").unwrap();
+        assert!(matches!(
+            Day08::part_two(&network),
+            Err(AocError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use aoc_common::parse::{parse_number, split_once_or_error};
+use aoc_common::{ParseError, Part};
+
+/// The known answers for the `input.txt` of every day, read from a small subset of TOML:
+///
+/// ```toml
+/// # Comments and empty lines are ignored
+/// [day05]
+/// part1 = 403695602
+/// part2 = "a string answer"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn parse(str: &str) -> Result<Answers, ParseError> {
+        let mut answers = BTreeMap::new();
+        let mut day: Option<u8> = None;
+
+        for line in str.lines().map(|x| x.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[') {
+                let (name, _) = split_once_or_error(section, ']')?;
+                day = Some(parse_day(name.trim())?);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| ParseError::new(line, "a section like [day01]"))?;
+            let (key, value) = split_once_or_error(line, '=')?;
+            let part = parse_part(key.trim())?;
+
+            answers.insert((day, part), parse_value(value.trim())?.to_string());
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|x| x.as_str())
+    }
+
+    /// Whether any answer is recorded for the day
+    pub fn has_day(&self, day: u8) -> bool {
+        self.get(day, Part::One).is_some() || self.get(day, Part::Two).is_some()
+    }
+}
+
+fn parse_day(name: &str) -> Result<u8, ParseError> {
+    let number = name
+        .strip_prefix("day")
+        .ok_or_else(|| ParseError::new(name, "a section like [day01]"))?;
+
+    match parse_number(number) {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(ParseError::new(number, "a day from 1 to 25")),
+    }
+}
+
+fn parse_part(key: &str) -> Result<Part, ParseError> {
+    match key {
+        "part1" => Ok(Part::One),
+        "part2" => Ok(Part::Two),
+        _ => Err(ParseError::new(key, "part1 or part2")),
+    }
+}
+
+/// A number or a quoted string, optionally followed by a comment
+fn parse_value(value: &str) -> Result<&str, ParseError> {
+    let (answer, rest) = match value.strip_prefix('"') {
+        Some(quoted) => split_once_or_error(quoted, '"')?,
+        None => value.split_at(value.find(['#', ' ', '\t']).unwrap_or(value.len())),
+    };
+
+    if answer.is_empty() && !value.starts_with('"') {
+        return Err(ParseError::new(answer, "an answer"));
+    }
+
+    let rest = rest.trim();
+
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(ParseError::new(rest, "a comment or the end of the line"));
+    }
+
+    Ok(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_answers() {
+        let answers = Answers::parse(
+            "# Known answers\n\n[day05]\npart1 = 403695602\n\n[day08]\npart2 = \"ABC # 1\" # comment",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(5, Part::One), Some("403695602"));
+        assert_eq!(answers.get(5, Part::Two), None);
+        assert_eq!(answers.get(8, Part::Two), Some("ABC # 1"));
+        assert!(answers.has_day(8));
+        assert!(!answers.has_day(1));
+    }
+
+    #[test]
+    fn reports_malformed_answers() {
+        let input = "[day05]\npart3 = 1";
+        let error = Answers::parse(input).unwrap_err().locate(input);
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "part1 or part2");
+
+        let input = "part1 = 1";
+        let error = Answers::parse(input).unwrap_err();
+        assert_eq!(error.expected, "a section like [day01]");
+
+        let input = "[day26]";
+        let error = Answers::parse(input).unwrap_err();
+        assert_eq!(error.expected, "a day from 1 to 25");
+
+        let input = "[day01]\npart1 = 12 34";
+        let error = Answers::parse(input).unwrap_err().locate(input);
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (2, 12, "34")
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::Write;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_common::input::read_file;
+use aoc_common::{AocError, Part, Solution};
+
+use crate::cli::BenchArgs;
+use crate::days;
+
+const HISTORY_HEADER: &str = "timestamp,commit,day,part,iterations,parse_min_ns,parse_median_ns,parse_max_ns,solve_min_ns,solve_median_ns,solve_max_ns";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `samples` must not be empty
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Parsing and solving are timed separately, so a slow parser does not hide in the solve time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Parse and solve the part `iterations` times
+pub fn bench_day<S: Solution>(
+    part: Part,
+    input: &str,
+    iterations: usize,
+) -> Result<Timings, AocError> {
+    let parse = time(iterations, || {
+        S::parse(input).map(|parsed| drop(black_box(parsed)))
+    })?;
+
+    let parsed = S::parse(input)?;
+    let solve = time(iterations, || {
+        let answer = match part {
+            Part::One => S::part_one(&parsed),
+            Part::Two => S::part_two(&parsed),
+        };
+
+        answer.map(|answer| drop(black_box(answer)))
+    })?;
+
+    Ok(Timings { parse, solve })
+}
+
+fn time<F>(iterations: usize, mut run: F) -> Result<Stats, AocError>
+where
+    F: FnMut() -> Result<(), AocError>,
+{
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        run()?;
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+/// The `SLOW_PARTS` are only timed when both their day and their part are given
+fn is_skipped(args: &BenchArgs, day: u8, part: Part) -> bool {
+    (args.day.is_none() || args.part.is_none()) && days::SLOW_PARTS.contains(&(day, part))
+}
+
+/// Time the requested days and parts, returns whether all of them could be solved
+pub fn bench(args: BenchArgs) -> Result<bool, AocError> {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::SOLVED_DAYS.collect(),
+    };
+
+    let history = match &args.history {
+        Some(path) if path.exists() => Some(read_file(path)?),
+        _ => None,
+    };
+
+    let commit = current_commit();
+    let mut records: Vec<String> = vec![];
+    let mut is_solved = true;
+
+    for day in days {
+        let source = days::input_source(day, args.inputs.as_deref());
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                is_solved = false;
+                continue;
+            }
+        };
+
+        for part in args.parts() {
+            if is_skipped(&args, day, part) {
+                println!(
+                    "Day {} part {}: skipped, too slow, bench it with --day {} --part {}",
+                    day, part, day, part
+                );
+                continue;
+            }
+
+            let timings = days::get(day)
+                .and_then(|solution| (solution.bench)(part, &input, args.iterations))
+                .map_err(|error| error.in_file(source.to_string()));
+
+            let timings = match timings {
+                Ok(timings) => timings,
+                Err(error) => {
+                    eprintln!("Day {} part {}: {}", day, part, error);
+                    is_solved = false;
+                    continue;
+                }
+            };
+
+            let previous = history
+                .as_deref()
+                .and_then(|history| last_solve_median(history, day, part));
+
+            print_timings(day, part, args.iterations, &timings, previous);
+            records.push(to_record(&commit, day, part, args.iterations, &timings));
+        }
+    }
+
+    if let Some(path) = &args.history {
+        append_history(path, &records)?;
+    }
+
+    Ok(is_solved)
+}
+
+fn print_timings(
+    day: u8,
+    part: Part,
+    iterations: usize,
+    timings: &Timings,
+    previous: Option<(String, Duration)>,
+) {
+    let format = |stats: &Stats| {
+        format!(
+            "min {:.1?}  median {:.1?}  max {:.1?}",
+            stats.min, stats.median, stats.max
+        )
+    };
+
+    println!("Day {} part {} ({} iterations)", day, part, iterations);
+    println!("    parse  {}", format(&timings.parse));
+
+    match previous {
+        Some((commit, median)) => println!(
+            "    solve  {}  ({} since {})",
+            format(&timings.solve),
+            change(median, timings.solve.median),
+            commit
+        ),
+        None => println!("    solve  {}", format(&timings.solve)),
+    }
+}
+
+/// The relative change of the median, like "+12.5%"
+fn change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return "n/a".to_string();
+    }
+
+    let percentage = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+
+    format!("{:+.1}%", percentage)
+}
+
+fn to_record(commit: &str, day: u8, part: Part, iterations: usize, timings: &Timings) -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{}",
+        timestamp,
+        commit,
+        day,
+        part,
+        iterations,
+        timings.parse.min.as_nanos(),
+        timings.parse.median.as_nanos(),
+        timings.parse.max.as_nanos(),
+        timings.solve.min.as_nanos(),
+        timings.solve.median.as_nanos(),
+        timings.solve.max.as_nanos()
+    )
+}
+
+/// The commit and the solve median of the latest run of the part in the history
+fn last_solve_median(history: &str, day: u8, part: Part) -> Option<(String, Duration)> {
+    history.lines().rev().find_map(|line| {
+        let fields: Vec<&str> = line.split(',').collect();
+
+        if fields.len() != 11 || fields[2] != day.to_string() || fields[3] != part.to_string() {
+            return None;
+        }
+
+        let median = fields[9].parse().ok().map(Duration::from_nanos)?;
+
+        Some((fields[1].to_string(), median))
+    })
+}
+
+fn append_history(path: &Path, records: &[String]) -> Result<(), AocError> {
+    let to_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let is_new = fs::metadata(path).map_or(true, |metadata| metadata.len() == 0);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(to_error)?;
+
+    if is_new {
+        writeln!(file, "{}", HISTORY_HEADER).map_err(to_error)?;
+    }
+
+    for record in records {
+        writeln!(file, "{}", record).map_err(to_error)?;
+    }
+
+    Ok(())
+}
+
+/// The short hash of the checked out commit, to tell the runs in the history apart
+fn current_commit() -> String {
+    process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or("unknown".to_string(), |commit| commit.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_millis(x)).collect()
+    }
+
+    #[test]
+    fn can_get_stats() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn can_find_the_last_run_in_the_history() {
+        let timings = Timings {
+            parse: Stats::from_samples(millis(&[1])),
+            solve: Stats::from_samples(millis(&[7])),
+        };
+
+        let history = [
+            HISTORY_HEADER.to_string(),
+            to_record("aaaaaaa", 4, Part::Two, 1, &timings),
+            to_record("bbbbbbb", 4, Part::Two, 1, &timings),
+            to_record("ccccccc", 4, Part::One, 1, &timings),
+        ]
+        .join("\n");
+
+        assert_eq!(
+            last_solve_median(&history, 4, Part::Two),
+            Some(("bbbbbbb".to_string(), Duration::from_millis(7)))
+        );
+        assert_eq!(last_solve_median(&history, 5, Part::Two), None);
+    }
+
+    #[test]
+    fn skips_slow_parts_unless_asked_for() {
+        let args = |day, part| BenchArgs {
+            day,
+            part,
+            inputs: None,
+            iterations: 10,
+            history: None,
+        };
+
+        assert!(is_skipped(&args(None, None), 8, Part::Two));
+        assert!(is_skipped(&args(Some(8), None), 8, Part::Two));
+        assert!(!is_skipped(&args(Some(8), Some(Part::Two)), 8, Part::Two));
+        assert!(!is_skipped(&args(Some(8), None), 8, Part::One));
+    }
+
+    #[test]
+    fn can_show_the_change() {
+        let before = Duration::from_millis(8);
+
+        assert_eq!(change(before, Duration::from_millis(9)), "+12.5%");
+        assert_eq!(change(before, Duration::from_millis(6)), "-25.0%");
+    }
+}
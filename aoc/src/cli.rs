@@ -0,0 +1,399 @@
+use std::path::PathBuf;
+
+use aoc_common::input::InputSource;
+use aoc_common::Part;
+
+use crate::output::Format;
+
+pub const USAGE: &str =
+    "Usage: aoc run --day <DAY> [--part <1|2>] [--input <PATH> | --inputs <DIR> | --example]
+                     [--format <text|json|csv>] [--details]
+       aoc verify [--day <DAY>] [--inputs <DIR>] [--answers <PATH>]
+       aoc bench [--day <DAY>] [--part <1|2>] [--inputs <DIR>] [--iterations <N>] [--history <PATH>]
+
+Commands:
+  run      Solve a day, both parts unless --part is given
+  verify   Check the answers of every day against the recorded ones
+  bench    Time parsing and solving of every day, or of the given day and part
+  help     Show this message
+
+Options:
+  -d, --day <DAY>        Day to solve, 1-25
+  -p, --part <PART>      Part to solve, 1 or 2
+  -i, --input <PATH>     Puzzle input, - reads it from stdin
+      --inputs <DIR>     Directory with the inputs of every day, like <DIR>/2023/05.txt,
+                         defaults to $AOC_INPUTS, without both every day reads <DAY>/input.txt
+  -e, --example          Solve the examples from the puzzle text and check their answers
+  -f, --format <FORMAT>  Print the answers as text, json or csv, defaults to text
+      --details          Also print what adds up to the answers, like the power of every game
+  -a, --answers <PATH>   Known answers, defaults to answers.toml
+  -n, --iterations <N>   Times to run every part, defaults to 10
+      --history <PATH>   Append the timings to this CSV file and compare with its last run
+  -v, --verbose          Log debug output to stderr
+      --trace            Also log every step of the solutions, this is a lot";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub day: u8,
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    pub inputs: Option<PathBuf>,
+    pub example: bool,
+    pub format: Format,
+    pub details: bool,
+}
+
+impl RunArgs {
+    /// The parts to solve, both when no part was given
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    /// Only verify this day instead of all solved days
+    pub day: Option<u8>,
+    pub inputs: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    /// Bench all solved days when no day is given
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub inputs: Option<PathBuf>,
+    pub iterations: usize,
+    pub history: Option<PathBuf>,
+}
+
+impl BenchArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Help,
+}
+
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run_args(args).map(Command::Run),
+        Some("verify") => parse_verify_args(args).map(Command::Verify),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some("help") | Some("-h") | Some("--help") | None => Ok(Command::Help),
+        Some(command) => Err(format!("Unknown command {:?}", command)),
+    }
+}
+
+fn parse_run_args<I: Iterator<Item = String>>(mut args: I) -> Result<RunArgs, String> {
+    let mut day: Option<u8> = None;
+    let mut part: Option<Part> = None;
+    let mut input: Option<InputSource> = None;
+    let mut inputs: Option<PathBuf> = None;
+    let mut example = false;
+    let mut format = Format::Text;
+    let mut details = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+
+        match arg.as_str() {
+            "-d" | "--day" => day = Some(parse_day(&value(&arg)?)?),
+            "-p" | "--part" => part = Some(parse_part(&value(&arg)?)?),
+            "-i" | "--input" => input = Some(InputSource::from_arg(&value(&arg)?)),
+            "--inputs" => inputs = Some(PathBuf::from(value(&arg)?)),
+            "-e" | "--example" => example = true,
+            "-f" | "--format" => format = parse_format(&value(&arg)?)?,
+            "--details" => details = true,
+            _ => return Err(format!("Unknown option {:?}", arg)),
+        }
+    }
+
+    let day = day.ok_or("Missing --day")?;
+
+    if [input.is_some(), inputs.is_some(), example]
+        .into_iter()
+        .filter(|&x| x)
+        .count()
+        > 1
+    {
+        return Err("Use only one of --input, --inputs and --example".to_string());
+    }
+
+    if example && (format != Format::Text || details) {
+        return Err("The examples are only printed as text, without details".to_string());
+    }
+
+    Ok(RunArgs {
+        day,
+        part,
+        input,
+        inputs,
+        example,
+        format,
+        details,
+    })
+}
+
+fn parse_verify_args<I: Iterator<Item = String>>(mut args: I) -> Result<VerifyArgs, String> {
+    let mut day: Option<u8> = None;
+    let mut inputs: Option<PathBuf> = None;
+    let mut answers: Option<PathBuf> = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+
+        match arg.as_str() {
+            "-d" | "--day" => day = Some(parse_day(&value(&arg)?)?),
+            "--inputs" => inputs = Some(PathBuf::from(value(&arg)?)),
+            "-a" | "--answers" => answers = Some(PathBuf::from(value(&arg)?)),
+            _ => return Err(format!("Unknown option {:?}", arg)),
+        }
+    }
+
+    Ok(VerifyArgs {
+        day,
+        inputs,
+        answers,
+    })
+}
+
+fn parse_bench_args<I: Iterator<Item = String>>(mut args: I) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        part: None,
+        inputs: None,
+        iterations: 10,
+        history: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+
+        match arg.as_str() {
+            "-d" | "--day" => bench_args.day = Some(parse_day(&value(&arg)?)?),
+            "-p" | "--part" => bench_args.part = Some(parse_part(&value(&arg)?)?),
+            "--inputs" => bench_args.inputs = Some(PathBuf::from(value(&arg)?)),
+            "-n" | "--iterations" => bench_args.iterations = parse_iterations(&value(&arg)?)?,
+            "--history" => bench_args.history = Some(PathBuf::from(value(&arg)?)),
+            _ => return Err(format!("Unknown option {:?}", arg)),
+        }
+    }
+
+    Ok(bench_args)
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Day should be a number from 1 to 25, got {:?}",
+            value
+        )),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Part should be 1 or 2, got {:?}", value)),
+    }
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err(format!(
+            "Format should be text, json or csv, got {:?}",
+            value
+        )),
+    }
+}
+
+fn parse_iterations(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(iterations) if iterations > 0 => Ok(iterations),
+        _ => Err(format!(
+            "Iterations should be a positive number, got {:?}",
+            value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(str: &str) -> Vec<String> {
+        str.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn can_parse_run() {
+        assert_eq!(
+            parse_args(args("run --day 5 --part 2 --input inputs/05.txt")),
+            Ok(Command::Run(RunArgs {
+                day: 5,
+                part: Some(Part::Two),
+                input: Some(InputSource::File(PathBuf::from("inputs/05.txt"))),
+                inputs: None,
+                example: false,
+                format: Format::Text,
+                details: false,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("run --day 5 --input -")),
+            Ok(Command::Run(RunArgs {
+                day: 5,
+                part: None,
+                input: Some(InputSource::Stdin),
+                inputs: None,
+                example: false,
+                format: Format::Text,
+                details: false,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("run --day 5 --inputs inputs")),
+            Ok(Command::Run(RunArgs {
+                day: 5,
+                part: None,
+                input: None,
+                inputs: Some(PathBuf::from("inputs")),
+                example: false,
+                format: Format::Text,
+                details: false,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("run -d 8 --example")),
+            Ok(Command::Run(RunArgs {
+                day: 8,
+                part: None,
+                input: None,
+                inputs: None,
+                example: true,
+                format: Format::Text,
+                details: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn can_parse_formats() {
+        let Ok(Command::Run(run_args)) = parse_args(args("run -d 2 --format json --details"))
+        else {
+            panic!("Should parse the run arguments");
+        };
+
+        assert_eq!((run_args.format, run_args.details), (Format::Json, true));
+        assert!(parse_args(args("run -d 2 --format xml")).is_err());
+        assert!(parse_args(args("run -d 2 --example --format csv")).is_err());
+    }
+
+    #[test]
+    fn can_parse_verify() {
+        assert_eq!(
+            parse_args(args("verify")),
+            Ok(Command::Verify(VerifyArgs {
+                day: None,
+                inputs: None,
+                answers: None,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("verify -d 4 --inputs inputs --answers old.toml")),
+            Ok(Command::Verify(VerifyArgs {
+                day: Some(4),
+                inputs: Some(PathBuf::from("inputs")),
+                answers: Some(PathBuf::from("old.toml")),
+            }))
+        );
+    }
+
+    #[test]
+    fn can_parse_bench() {
+        assert_eq!(
+            parse_args(args("bench -d 4 -p 2 -n 50 --history bench.csv")),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(4),
+                part: Some(Part::Two),
+                inputs: None,
+                iterations: 50,
+                history: Some(PathBuf::from("bench.csv")),
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("bench")),
+            Ok(Command::Bench(BenchArgs {
+                day: None,
+                part: None,
+                inputs: None,
+                iterations: 10,
+                history: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run --day 26")).is_err());
+        assert!(parse_args(args("run --day 1 --part 3")).is_err());
+        assert!(parse_args(args("run --day")).is_err());
+        assert!(parse_args(args("run --day 1 --verbose")).is_err());
+        assert!(parse_args(args("run --day 1 --example --input 01.txt")).is_err());
+        assert!(parse_args(args("run --day 1 --inputs inputs --input 01.txt")).is_err());
+        assert!(parse_args(args("verify --part 1")).is_err());
+        assert!(parse_args(args("bench --iterations 0")).is_err());
+        assert!(parse_args(args("walk")).is_err());
+    }
+
+    #[test]
+    fn can_get_parts() {
+        let run_args = RunArgs {
+            day: 1,
+            part: None,
+            input: None,
+            inputs: None,
+            example: false,
+            format: Format::Text,
+            details: false,
+        };
+
+        assert_eq!(run_args.parts(), vec![Part::One, Part::Two]);
+    }
+}
@@ -0,0 +1,129 @@
+use std::env;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use advent_of_code_01::Day01;
+use advent_of_code_02::Day02;
+use advent_of_code_03::Day03;
+use advent_of_code_04::Day04;
+use advent_of_code_05::Day05;
+use advent_of_code_06::Day06;
+use advent_of_code_07::Day07;
+use advent_of_code_08::Day08;
+use aoc_common::input::InputSource;
+use aoc_common::solution::run_examples;
+use aoc_common::{AocError, Detail, Part, Solution};
+
+use crate::bench::{bench_day, Timings};
+
+/// The days `solve` knows about
+pub const SOLVED_DAYS: RangeInclusive<u8> = 1..=8;
+
+/// The inputs directory when --inputs is not given
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// The input file of a day when no path is given, relative to the repository root
+pub fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(format!("{:02}", day)).join("input.txt")
+}
+
+/// The input of a day in the inputs directory when there is one, else its default input
+pub fn input_source(day: u8, inputs: Option<&Path>) -> InputSource {
+    let dir = inputs
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(INPUTS_ENV).map(PathBuf::from));
+
+    match dir {
+        Some(dir) => InputSource::in_dir(dir, day),
+        None => InputSource::File(default_input(day)),
+    }
+}
+
+/// Parts that take too long to run them unasked, `bench` only runs them for an explicit day
+pub const SLOW_PARTS: [(u8, Part); 1] = [(8, Part::Two)];
+
+/// What the runner can do with the solution of one day
+pub struct Day {
+    pub solve: fn(Part, &str) -> Result<String, AocError>,
+    pub details: fn(Part, &str) -> Result<Vec<Detail>, AocError>,
+    pub bench: fn(Part, &str, usize) -> Result<Timings, AocError>,
+    /// Solve the registered examples, printing the results under the label
+    pub run_examples: fn(&str, &[Part]) -> bool,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Day {
+        Day {
+            solve: solve_day::<S>,
+            details: details_of_day::<S>,
+            bench: bench_day::<S>,
+            run_examples: run_examples::<S>,
+        }
+    }
+}
+
+pub fn get(day: u8) -> Result<Day, AocError> {
+    match day {
+        1 => Ok(Day::of::<Day01>()),
+        2 => Ok(Day::of::<Day02>()),
+        3 => Ok(Day::of::<Day03>()),
+        4 => Ok(Day::of::<Day04>()),
+        5 => Ok(Day::of::<Day05>()),
+        6 => Ok(Day::of::<Day06>()),
+        7 => Ok(Day::of::<Day07>()),
+        8 => Ok(Day::of::<Day08>()),
+        _ => Err(AocError::Unsupported(format!(
+            "Day {} is not solved yet",
+            day
+        ))),
+    }
+}
+
+pub fn solve(day: u8, part: Part, input: &str) -> Result<String, AocError> {
+    (get(day)?.solve)(part, input)
+}
+
+fn solve_day<S: Solution>(part: Part, input: &str) -> Result<String, AocError> {
+    S::solve(input, part).map(|answer| answer.to_string())
+}
+
+fn details_of_day<S: Solution>(part: Part, input: &str) -> Result<Vec<Detail>, AocError> {
+    S::details(&S::parse(input)?, part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_solve_a_day() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+
+        assert_eq!(solve(6, Part::One, input).unwrap(), "288");
+        assert_eq!(solve(6, Part::Two, input).unwrap(), "71503");
+    }
+
+    #[test]
+    fn can_solve_all_examples() {
+        for day in SOLVED_DAYS {
+            let solution = get(day).unwrap();
+            let label = format!("Day {}", day);
+
+            assert!((solution.run_examples)(&label, &[Part::One, Part::Two]));
+        }
+    }
+
+    #[test]
+    fn reports_unsolved_days() {
+        assert!(solve(25, Part::One, "").is_err());
+    }
+
+    #[test]
+    fn can_get_default_input() {
+        assert_eq!(default_input(5), PathBuf::from("05/input.txt"));
+        assert_eq!(
+            input_source(5, Some(Path::new("inputs"))),
+            InputSource::File(PathBuf::from("inputs/2023/05.txt"))
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::env;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc_common::{log, AocError};
+
+mod answers;
+mod bench;
+mod cli;
+mod days;
+mod output;
+mod verify;
+
+use cli::{Command, RunArgs};
+use output::PartResult;
+
+fn main() -> ExitCode {
+    // Debug output goes to stderr, the answers stay alone on stdout
+    let args = log::init(env::args().skip(1));
+
+    let command = match cli::parse_args(args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(true)
+        }
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify::verify(args),
+        Command::Bench(args) => bench::bench(args),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solve the requested parts, returns whether all of them could be solved
+fn run(args: RunArgs) -> Result<bool, AocError> {
+    if args.example {
+        let solution = days::get(args.day)?;
+        let label = format!("Day {}", args.day);
+
+        return Ok((solution.run_examples)(&label, &args.parts()));
+    }
+
+    let source = args
+        .input
+        .clone()
+        .unwrap_or_else(|| days::input_source(args.day, args.inputs.as_deref()));
+    let input = source.read()?;
+
+    let solution = days::get(args.day)?;
+    let mut results: Vec<PartResult> = vec![];
+
+    // Keep solving the other part when one of them fails
+    for part in args.parts() {
+        let start = Instant::now();
+        let answer = (solution.solve)(part, &input);
+        let time = start.elapsed();
+
+        let (answer, details) = match answer {
+            Ok(answer) if args.details => match (solution.details)(part, &input) {
+                Ok(details) => (Ok(answer), details),
+                Err(error) => (Err(error), vec![]),
+            },
+            answer => (answer, vec![]),
+        };
+
+        results.push(PartResult {
+            day: args.day,
+            part,
+            answer: answer.map_err(|error| error.in_file(source.to_string())),
+            time,
+            details,
+        });
+    }
+
+    output::print(args.format, &results, args.details);
+
+    Ok(results.iter().all(|result| result.answer.is_ok()))
+}
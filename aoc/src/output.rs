@@ -0,0 +1,211 @@
+use std::time::Duration;
+
+use aoc_common::{AocError, Detail, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// The outcome of solving one part
+#[derive(Debug)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<String, AocError>,
+    /// Parsing and solving together
+    pub time: Duration,
+    pub details: Vec<Detail>,
+}
+
+/// Print the results, errors go to stderr in the text format and into the output otherwise
+pub fn print(format: Format, results: &[PartResult], with_details: bool) {
+    match format {
+        Format::Text => print_text(results),
+        Format::Json => println!("{}", to_json(results, with_details)),
+        Format::Csv => print!("{}", to_csv(results, with_details)),
+    }
+}
+
+fn print_text(results: &[PartResult]) {
+    for result in results {
+        match &result.answer {
+            Ok(answer) => println!("Day {} part {}: {}", result.day, result.part, answer),
+            Err(error) => eprintln!("Day {} part {}: {}", result.day, result.part, error),
+        }
+
+        for detail in &result.details {
+            println!("    {}: {}", detail.item, detail.value);
+        }
+    }
+}
+
+fn to_json(results: &[PartResult], with_details: bool) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let mut fields = vec![
+                format!("\"day\": {}", result.day),
+                format!("\"part\": {}", result.part),
+            ];
+
+            match &result.answer {
+                Ok(answer) => fields.push(format!("\"answer\": {}", json_string(answer))),
+                Err(error) => {
+                    fields.push("\"answer\": null".to_string());
+                    fields.push(format!("\"error\": {}", json_string(&error.to_string())));
+                }
+            }
+
+            fields.push(format!("\"time_ns\": {}", result.time.as_nanos()));
+
+            if with_details {
+                let details: Vec<String> = result
+                    .details
+                    .iter()
+                    .map(|detail| {
+                        format!(
+                            "{{\"item\": {}, \"value\": {}}}",
+                            json_string(&detail.item),
+                            json_string(&detail.value)
+                        )
+                    })
+                    .collect();
+
+                fields.push(format!("\"details\": [{}]", details.join(", ")));
+            }
+
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect();
+
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+/// One row per answer, or one row per item with details
+///
+/// With details every row starts with the answer, time and error of its part, and a part
+/// without items, like a failed one, still gets a row with an empty item and value.
+fn to_csv(results: &[PartResult], with_details: bool) -> String {
+    let mut header = "day,part,answer,time_ns,error".to_string();
+
+    if with_details {
+        header.push_str(",item,value");
+    }
+
+    let mut rows = vec![header];
+
+    for result in results {
+        let (answer, error) = match &result.answer {
+            Ok(answer) => (csv_field(answer), String::new()),
+            Err(error) => (String::new(), csv_field(&error.to_string())),
+        };
+        let row = format!(
+            "{},{},{},{},{}",
+            result.day,
+            result.part,
+            answer,
+            result.time.as_nanos(),
+            error
+        );
+
+        if !with_details {
+            rows.push(row);
+        } else if result.details.is_empty() {
+            rows.push(format!("{},,", row));
+        } else {
+            for detail in &result.details {
+                rows.push(format!(
+                    "{},{},{}",
+                    row,
+                    csv_field(&detail.item),
+                    csv_field(&detail.value)
+                ));
+            }
+        }
+    }
+
+    rows.iter().map(|row| format!("{}\n", row)).collect()
+}
+
+fn json_string(str: &str) -> String {
+    let mut escaped = String::from('"');
+
+    for ch in str.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// Quote the field when it contains a comma, a quote or a line break
+fn csv_field(str: &str) -> String {
+    if str.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", str.replace('"', "\"\""))
+    } else {
+        str.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                day: 2,
+                part: Part::Two,
+                answer: Ok("2286".to_string()),
+                time: Duration::from_nanos(1500),
+                details: vec![Detail::new("Game 1", 48), Detail::new("Game, \"2\"", 12)],
+            },
+            PartResult {
+                day: 1,
+                part: Part::One,
+                answer: Err(AocError::Unsupported("Not implemented".to_string())),
+                time: Duration::from_nanos(20),
+                details: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn can_write_json() {
+        assert_eq!(
+            to_json(&results(), false),
+            "[\n  {\"day\": 2, \"part\": 2, \"answer\": \"2286\", \"time_ns\": 1500},\n  {\"day\": 1, \"part\": 1, \"answer\": null, \"error\": \"Not implemented\", \"time_ns\": 20}\n]"
+        );
+
+        assert!(to_json(&results(), true).contains(
+            "\"details\": [{\"item\": \"Game 1\", \"value\": \"48\"}, {\"item\": \"Game, \\\"2\\\"\", \"value\": \"12\"}]"
+        ));
+    }
+
+    #[test]
+    fn can_write_csv() {
+        assert_eq!(
+            to_csv(&results(), false),
+            "day,part,answer,time_ns,error\n2,2,2286,1500,\n1,1,,20,Not implemented\n"
+        );
+
+        assert_eq!(
+            to_csv(&results(), true),
+            "day,part,answer,time_ns,error,item,value\n\
+             2,2,2286,1500,,Game 1,48\n\
+             2,2,2286,1500,,\"Game, \"\"2\"\"\",12\n\
+             1,1,,20,Not implemented,,\n"
+        );
+    }
+}
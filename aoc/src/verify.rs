@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use aoc_common::input::read_file;
+use aoc_common::solution::check_answer;
+use aoc_common::{AocError, Part};
+
+use crate::answers::Answers;
+use crate::cli::VerifyArgs;
+use crate::days;
+
+/// The answers file when no path is given, relative to the repository root
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Summary {
+    correct: usize,
+    wrong: usize,
+    skipped: usize,
+}
+
+/// Solve every recorded answer again, returns whether all of them still match
+pub fn verify(args: VerifyArgs) -> Result<bool, AocError> {
+    let path = args
+        .answers
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS));
+    let answers =
+        Answers::parse(&read_file(&path)?).map_err(|error| AocError::from(error).in_file(&path))?;
+
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::SOLVED_DAYS.collect(),
+    };
+
+    let mut summary = Summary::default();
+
+    for day in days {
+        if !answers.has_day(day) {
+            println!("Day {}: skipped, no answers recorded", day);
+            summary.skipped += 2;
+            continue;
+        }
+
+        let source = days::input_source(day, args.inputs.as_deref());
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                summary.wrong += 2;
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = answers.get(day, part) else {
+                println!("Day {} part {}: skipped, no answer recorded", day, part);
+                summary.skipped += 1;
+                continue;
+            };
+
+            let found =
+                days::solve(day, part, &input).map_err(|error| error.in_file(source.to_string()));
+
+            match check_answer(expected, found) {
+                Ok(()) => {
+                    println!("Day {} part {}: ok", day, part);
+                    summary.correct += 1;
+                }
+                Err(message) => {
+                    eprintln!("Day {} part {}: {}", day, part, message);
+                    summary.wrong += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{} correct, {} wrong, {} skipped",
+        summary.correct, summary.wrong, summary.skipped
+    );
+
+    Ok(summary.wrong == 0)
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file could not be read
+    Io { path: PathBuf, source: io::Error },
+    /// The puzzle input does not exist
+    MissingInput(PathBuf),
+    /// The input could not be parsed
+    Parse(ParseError),
+    /// The requested day or part is not available
+    Unsupported(String),
+    /// A number does not fit in its type
+    Overflow(String),
+    /// The input is well-formed but does not fit the configuration it is solved with
+    Invalid(String),
+}
+
+impl AocError {
+    /// Attach the input file to a parse error, or to a read error from a reader without a path
+    pub fn in_file<P: AsRef<Path>>(self, path: P) -> Self {
+        match self {
+            AocError::Parse(error) => AocError::Parse(error.in_file(path)),
+            AocError::Io {
+                path: empty,
+                source,
+            } if empty.as_os_str().is_empty() => AocError::Io {
+                path: path.as_ref().to_path_buf(),
+                source,
+            },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => {
+                write!(f, "Cannot read file {}: {}", path.display(), source)
+            }
+            AocError::MissingInput(path) => {
+                write!(f, "Cannot find the puzzle input {}", path.display())
+            }
+            AocError::Parse(error) => write!(f, "{}", error),
+            AocError::Unsupported(message)
+            | AocError::Overflow(message)
+            | AocError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Parse(error) => Some(error),
+            AocError::MissingInput(_)
+            | AocError::Unsupported(_)
+            | AocError::Overflow(_)
+            | AocError::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::Parse(error)
+    }
+}
+
+/// An unexpected token in the puzzle input
+///
+/// Parsers create it from the offending slice of the input. The function that
+/// sees the whole input calls `locate` to turn that slice into a line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// 1-based, 0 when the error has not been located
+    pub line: usize,
+    /// 1-based and counted in characters, 0 when the error has not been located
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    /// The whole line containing the error, to point at the column
+    pub source_line: Option<String>,
+    token_address: usize,
+}
+
+impl ParseError {
+    /// `token` must be a slice of the input, an empty slice marks a missing token
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            found: token.to_string(),
+            source_line: None,
+            token_address: token.as_ptr() as usize,
+        }
+    }
+
+    /// Find the line and column of the token in the input it was sliced from
+    ///
+    /// Tokens that are not part of `input` are left as they are.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+
+        if self.token_address < start || self.token_address > start + input.len() {
+            return self;
+        }
+
+        let offset = self.token_address - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        self.line = input[..offset].matches('\n').count() + 1;
+        self.column = input[line_start..offset].chars().count() + 1;
+        self.source_line = Some(
+            input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        );
+
+        self
+    }
+
+    /// Like `locate`, for parsers that only see one line at a time, `line_number` is 1-based
+    pub fn locate_in_line(self, line_number: usize, line: &str) -> Self {
+        let mut error = self.locate(line);
+
+        if error.line > 0 {
+            error.line = line_number;
+        }
+
+        error
+    }
+
+    pub fn in_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.file = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+
+        if self.line > 0 {
+            write!(f, "{}:{}: ", self.line, self.column)?;
+        } else if self.file.is_some() {
+            write!(f, " ")?;
+        }
+
+        if self.found.is_empty() {
+            write!(f, "expected {}, found end of line", self.expected)?;
+        } else {
+            write!(f, "expected {}, found {:?}", self.expected, self.found)?;
+        }
+
+        if let Some(source_line) = &self.source_line {
+            write!(
+                f,
+                "\n    {}\n    {}^",
+                source_line,
+                " ".repeat(self.column - 1)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Print the error and exit instead of panicking, for the binaries of the days
+pub trait OrExit<T> {
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: fmt::Display> OrExit<T> for Result<T, E> {
+    fn or_exit(self) -> T {
+        self.unwrap_or_else(|error| {
+            eprintln!("Error: {}", error);
+            process::exit(1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_locate_a_token() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30";
+        let token = &input[33..35];
+
+        let error = ParseError::new(token, "a number").locate(input);
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 12);
+        assert_eq!(error.source_line.as_deref(), Some("Card 2: 13 x2 | 61 30"));
+        assert_eq!(
+            error.in_file("input.txt").to_string(),
+            "input.txt:2:12: expected a number, found \"x2\"\n    Card 2: 13 x2 | 61 30\n               ^"
+        );
+    }
+
+    #[test]
+    fn can_locate_a_missing_token() {
+        let input = "32T3K 765\nT55J5";
+        let error = ParseError::new(&input[input.len()..], "a bid").locate(input);
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert!(error
+            .to_string()
+            .starts_with("2:6: expected a bid, found end of line"));
+    }
+
+    #[test]
+    fn can_locate_a_token_in_a_line() {
+        let line = "Card 2: 13 x2 | 61 30";
+        let error = ParseError::new(&line[11..13], "a number").locate_in_line(7, line);
+
+        assert_eq!((error.line, error.column), (7, 12));
+        assert_eq!(error.source_line.as_deref(), Some(line));
+    }
+
+    #[test]
+    fn keeps_tokens_from_other_strings_unlocated() {
+        let token = String::from("x");
+        let error = ParseError::new(&token, "a number").locate("1 2 3");
+
+        assert_eq!((error.line, error.column), (0, 0));
+        assert_eq!(error.to_string(), "expected a number, found \"x\"");
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// A cell of a grid, (x, y) with y counting the rows from the top
+pub type Point = (usize, usize);
+
+/// A rectangle of cells stored row after row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` × `height` copies of `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// None outside of the grid
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[self.index(point)])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        let index = self.index(point);
+        Some(&mut self.cells[index])
+    }
+
+    fn index(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+
+    /// The cells above, left, right and below that are in the grid
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(point, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// The cells around the point, diagonals included, that are in the grid, row by row
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(
+            point,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors(
+        &self,
+        (x, y): Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbor = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+            self.contains(neighbor).then_some(neighbor)
+        })
+    }
+
+    /// Every point in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every point with its cell in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The row at `y`, which must be in the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The rows from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of the column at `x` from the top, `x` must be in the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The columns from the left
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// One row per line, ignoring indentation
+    ///
+    /// Every row must be as wide as the first one.
+    pub fn parse(str: &str) -> Result<Grid<char>, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in str.lines().map(str::trim) {
+            let len = line.chars().count();
+
+            match width {
+                Some(width) if width != len => {
+                    return Err(ParseError::new(line, format!("a row of {} cells", width)))
+                }
+                _ => width = Some(len),
+            }
+
+            cells.extend(line.chars());
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Panics outside of the grid, see `get`
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+/// The rows on their own lines, the cells without separators
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\nghi").unwrap()
+    }
+
+    #[test]
+    fn can_parse_a_grid() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(1, 0)], 'b');
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid.get((3, 1)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\nghi");
+        assert_eq!(Grid::parse("  4.\n  .*").unwrap().to_string(), "4.\n.*");
+        assert_eq!(Grid::parse("").unwrap().height(), 0);
+    }
+
+    #[test]
+    fn reports_ragged_rows() {
+        let input = "abc\nde";
+        let error = Grid::parse(input).unwrap_err().locate(input);
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of 3 cells");
+    }
+
+    #[test]
+    fn can_find_neighbors() {
+        let grid = grid();
+        let neighbors4: Vec<Point> = grid.neighbors4((1, 1)).collect();
+        let neighbors8: Vec<Point> = grid.neighbors8((1, 1)).collect();
+
+        assert_eq!(neighbors4, [(1, 0), (0, 1), (2, 1), (1, 2)]);
+        assert_eq!(
+            neighbors8,
+            [
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (0, 1),
+                (2, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2)
+            ]
+        );
+
+        // The corners have fewer neighbors
+        assert_eq!(
+            grid.neighbors8((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(
+            grid.neighbors4((2, 2)).collect::<Vec<_>>(),
+            [(2, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn can_iterate_rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+
+        assert_eq!(rows, ["abc", "def", "ghi"]);
+        assert_eq!(columns, ["adg", "beh", "cfi"]);
+        assert_eq!(grid.iter().nth(5), Some(((2, 1), &'f')));
+    }
+
+    #[test]
+    fn can_change_cells() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[(1, 0)] = 5;
+        *grid.get_mut((0, 1)).unwrap() += 1;
+
+        assert_eq!(grid.to_string(), "05\n10");
+        assert_eq!(grid.map(|cell| cell * 2).row(0), [0, 10]);
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use std::fs::{read_to_string, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::AocError;
+
+/// The year of the puzzles, inputs directories keep one folder per year
+pub const YEAR: u16 = 2023;
+
+/// Where to read the puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is a path
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// The input of the day in an inputs directory, like inputs/2023/05.txt
+    pub fn in_dir<P: AsRef<Path>>(dir: P, day: u8) -> InputSource {
+        let file_name = format!("{:02}.txt", day);
+
+        InputSource::File(dir.as_ref().join(YEAR.to_string()).join(file_name))
+    }
+
+    /// The first argument that is not an option, or `default` when there is none
+    pub fn from_args(args: &[String], default: &str) -> InputSource {
+        let arg = args
+            .iter()
+            .find(|arg| arg.as_str() == "-" || !arg.starts_with('-'))
+            .map_or(default, |arg| arg.as_str());
+
+        InputSource::from_arg(arg)
+    }
+
+    /// Like `from_args`, defaulting to the `input.txt` of the crate in `manifest_dir`
+    pub fn from_args_in_crate(args: &[String], manifest_dir: &str) -> InputSource {
+        InputSource::from_args(args, &format!("{}/input.txt", manifest_dir))
+    }
+
+    pub fn read(&self) -> Result<String, AocError> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| AocError::Io {
+                        path: PathBuf::from(self.to_string()),
+                        source,
+                    })?;
+
+                Ok(input)
+            }
+            InputSource::File(path) if !path.exists() => {
+                Err(AocError::MissingInput(path.to_path_buf()))
+            }
+            InputSource::File(path) => read_file(path),
+        }
+    }
+
+    /// A buffered reader over the input, to stream inputs that are too large to read at once
+    pub fn open(&self) -> Result<Box<dyn BufRead>, AocError> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) if !path.exists() => {
+                Err(AocError::MissingInput(path.to_path_buf()))
+            }
+            InputSource::File(path) => {
+                let file = File::open(path).map_err(|source| AocError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Read the whole file into a string
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, AocError> {
+    let path = path.as_ref();
+
+    read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_missing_path() {
+        let error = read_file("does-not-exist.txt").unwrap_err();
+
+        assert!(error.to_string().contains("does-not-exist.txt"));
+    }
+
+    #[test]
+    fn can_get_input_sources() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::in_dir("inputs", 5),
+            InputSource::File(PathBuf::from("inputs/2023/05.txt"))
+        );
+
+        let args = ["--example".to_string(), "05.txt".to_string()];
+        assert_eq!(
+            InputSource::from_args(&args, "input.txt"),
+            InputSource::File(PathBuf::from("05.txt"))
+        );
+        assert_eq!(
+            InputSource::from_args(&args[..1], "input.txt"),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+        assert_eq!(
+            InputSource::from_args(&["-".to_string()], "input.txt"),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_args_in_crate(&args[..1], "/aoc/05"),
+            InputSource::File(PathBuf::from("/aoc/05/input.txt"))
+        );
+    }
+
+    #[test]
+    fn reports_a_missing_input() {
+        let error = InputSource::from_arg("inputs/2023/26.txt")
+            .read()
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Cannot find the puzzle input inputs/2023/26.txt"
+        );
+    }
+}
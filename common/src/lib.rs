@@ -0,0 +1,15 @@
+//! Helpers shared by all the Advent of Code days: reading the puzzle input,
+//! parsing whitespace separated numbers, the `Grid` of the grid puzzles, the
+//! error type they report, opt-in debug logging and the `Solution` trait every
+//! day implements.
+
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod solution;
+
+pub use error::{AocError, OrExit, ParseError};
+pub use grid::{Grid, Point};
+pub use solution::{Detail, Example, Part, Solution};
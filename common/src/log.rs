@@ -0,0 +1,100 @@
+//! Opt-in debug output, written to stderr so it never mixes with the answers on stdout
+//!
+//! `--verbose` enables `debug!`, `--trace` also enables the per-step `trace!` output.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Debug,
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Off,
+        1 => Level::Debug,
+        _ => Level::Trace,
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+/// Set the level from `--verbose` / `--trace` and return the other arguments
+pub fn init<I: IntoIterator<Item = String>>(args: I) -> Vec<String> {
+    let mut level = Level::Off;
+    let mut rest = vec![];
+
+    for arg in args {
+        match arg.as_str() {
+            "-v" | "--verbose" => level = level.max(Level::Debug),
+            "--trace" => level = Level::Trace,
+            _ => rest.push(arg),
+        }
+    }
+
+    set_level(level);
+
+    rest
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, message: fmt::Arguments) {
+    let name = match level {
+        Level::Trace => "TRACE",
+        _ => "DEBUG",
+    };
+
+    eprintln!("[{} {}] {}", name, module, message);
+}
+
+/// Log a message when `--verbose` or `--trace` is given
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// Log a message when `--trace` is given, for output on every step of a loop
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_init_from_args() {
+        let args = ["run", "--verbose", "--day", "5"].map(String::from);
+
+        assert_eq!(init(args), vec!["run", "--day", "5"]);
+        assert!(enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+
+        init(["--trace".to_string(), "-v".to_string()]);
+        assert!(enabled(Level::Trace));
+
+        init(vec![]);
+        assert_eq!(level(), Level::Off);
+        assert!(!enabled(Level::Off));
+    }
+}
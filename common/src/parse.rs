@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// Parse a string of numbers separated by any amount of whitespace
+///
+/// "  1 21 53  59" => [1, 21, 53, 59]
+pub fn parse_numbers<T: FromStr>(str: &str) -> Result<Vec<T>, ParseError> {
+    str.split_whitespace().map(parse_number).collect()
+}
+
+pub fn parse_number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(token, "a number"))
+}
+
+/// Parse the numbers behind a label, like "Time:      7  15   30"
+pub fn parse_labeled_numbers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    let (_, numbers) = split_once_or_error(line, ':')?;
+
+    parse_numbers(numbers)
+}
+
+/// Split the string on the first delimiter, an error points at the end of the string when it is missing
+pub fn split_once_or_error(str: &str, delimiter: char) -> Result<(&str, &str), ParseError> {
+    str.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(&str[str.len()..], format!("{:?}", delimiter)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_numbers() {
+        assert_eq!(
+            parse_numbers::<u32>(" 1 21  53 59 ").unwrap(),
+            vec![1, 21, 53, 59]
+        );
+        assert_eq!(parse_numbers::<i64>("-4 8").unwrap(), vec![-4, 8]);
+        assert_eq!(parse_numbers::<u32>("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn reports_the_invalid_number() {
+        let input = "1 x 3";
+        let error = parse_numbers::<u32>(input).unwrap_err().locate(input);
+
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.expected, "a number");
+        assert_eq!(error.found, "x");
+    }
+
+    #[test]
+    fn can_parse_labeled_numbers() {
+        assert_eq!(
+            parse_labeled_numbers::<i64>("Time:      7  15   30").unwrap(),
+            vec![7, 15, 30]
+        );
+
+        let input = "7 15 30";
+        let error = parse_labeled_numbers::<i64>(input)
+            .unwrap_err()
+            .locate(input);
+
+        assert_eq!((error.expected.as_str(), error.column), ("':'", 8));
+    }
+}
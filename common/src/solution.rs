@@ -0,0 +1,188 @@
+use std::{env, fmt, process};
+
+use crate::input::InputSource;
+use crate::{log, AocError, OrExit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// An example input from the puzzle text, with the answers the puzzle gives for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    /// Examples often only belong to one of the parts
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+/// The value of one item that adds up to an answer, like the power of one game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detail {
+    pub item: String,
+    pub value: String,
+}
+
+impl Detail {
+    pub fn new(item: impl ToString, value: impl ToString) -> Detail {
+        Detail {
+            item: item.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// A puzzle of one day: parse the input once, then solve both parts on it
+pub trait Solution {
+    /// The parsed puzzle input, may borrow from the raw input
+    type Input<'a>;
+    type Answer: fmt::Display;
+
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(raw_input: &str) -> Result<Self::Input<'_>, AocError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::Answer, AocError>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::Answer, AocError>;
+
+    /// The per-item breakdown of the answer of the part, days without one return nothing
+    fn details(_input: &Self::Input<'_>, _part: Part) -> Result<Vec<Detail>, AocError> {
+        Ok(vec![])
+    }
+
+    /// Parse the raw input and solve the given part
+    fn solve(raw_input: &str, part: Part) -> Result<Self::Answer, AocError> {
+        let input = Self::parse(raw_input)?;
+
+        match part {
+            Part::One => Self::part_one(&input),
+            Part::Two => Self::part_two(&input),
+        }
+    }
+}
+
+/// Solve the parts on every example that has an answer for them and print the results
+///
+/// Returns whether all answers were as expected.
+pub fn run_examples<S: Solution>(label: &str, parts: &[Part]) -> bool {
+    let mut is_correct = true;
+
+    for (index, example) in S::EXAMPLES.iter().enumerate() {
+        for &part in parts {
+            let Some(expected) = example.answer(part) else {
+                continue;
+            };
+
+            let found = S::solve(example.input, part).map(|answer| answer.to_string());
+
+            match check_answer(expected, found) {
+                Ok(()) => println!(
+                    "{} part {} (example {}): {}",
+                    label,
+                    part,
+                    index + 1,
+                    expected
+                ),
+                Err(message) => {
+                    eprintln!(
+                        "{} part {} (example {}): {}",
+                        label,
+                        part,
+                        index + 1,
+                        message
+                    );
+                    is_correct = false;
+                }
+            }
+        }
+    }
+
+    is_correct
+}
+
+/// The arguments of a day binary without the logging options
+///
+/// With `--example` it solves the examples instead and exits, with 1 when an answer is wrong.
+pub fn day_args<S: Solution>(label: &str) -> Vec<String> {
+    let args = log::init(env::args().skip(1));
+
+    if args.iter().any(|arg| arg == "--example") {
+        let is_correct = run_examples::<S>(label, &[Part::One, Part::Two]);
+        process::exit(if is_correct { 0 } else { 1 });
+    }
+
+    args
+}
+
+/// The `main` of a day binary without options of its own: solve both parts and print them
+///
+/// The input is the first argument, or the `input.txt` of the crate in `manifest_dir`,
+/// so the binary works from any directory.
+pub fn run_day<S: Solution>(label: &str, manifest_dir: &str) {
+    let args = day_args::<S>(label);
+    let source = InputSource::from_args_in_crate(&args, manifest_dir);
+    let input = source.read().or_exit();
+    let input = S::parse(&input)
+        .map_err(|error| error.in_file(source.to_string()))
+        .or_exit();
+
+    let result_part_one = S::part_one(&input).or_exit();
+    println!("result_part_one: {}", result_part_one);
+
+    let result_part_two = S::part_two(&input).or_exit();
+    println!("result_part_two: {}", result_part_two);
+}
+
+/// Compare an answer with the expected one, the error shows the difference
+pub fn check_answer(expected: &str, found: Result<String, AocError>) -> Result<(), String> {
+    match found {
+        Ok(answer) if answer == expected => Ok(()),
+        Ok(answer) => Err(format!(
+            "wrong answer\n    - {} (expected)\n    + {} (found)",
+            expected, answer
+        )),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_check_answers() {
+        assert_eq!(check_answer("288", Ok("288".to_string())), Ok(()));
+        assert_eq!(
+            check_answer("288", Ok("289".to_string())),
+            Err("wrong answer\n    - 288 (expected)\n    + 289 (found)".to_string())
+        );
+        assert_eq!(
+            check_answer(
+                "288",
+                Err(AocError::Unsupported("Not implemented".to_string()))
+            ),
+            Err("Not implemented".to_string())
+        );
+    }
+}